@@ -41,6 +41,26 @@ fn main() {
         );
     }
 
+    // LOG_LEVEL is set through CFLAGS (eg. `CFLAGS += -DLOG_LEVEL=LOG_DEBUG`) if at all, and then
+    // shows up as a define in riotbuild.h; otherwise, log.h defaults to LOG_INFO.
+    const LOG_LEVELS: &[&str] = &["none", "error", "warning", "info", "debug", "all"];
+    println!(
+        "cargo::rustc-check-cfg=cfg(riot_log_level, values(\"{}\"))",
+        LOG_LEVELS.join("\", \"")
+    );
+    if let Some(level) = defines.get("LOG_LEVEL") {
+        let level = level.trim_start_matches('(').trim_end_matches(')');
+        let level = match level.parse::<usize>() {
+            Ok(n) => LOG_LEVELS.get(n).copied(),
+            Err(_) => level
+                .strip_prefix("LOG_")
+                .map(|name| name.to_lowercase())
+                .and_then(|name| LOG_LEVELS.iter().find(|l| **l == name).copied()),
+        };
+        let level = level.expect("LOG_LEVEL in riotbuild.h is not a known log level");
+        println!("cargo:rustc-cfg=riot_log_level=\"{level}\"");
+    }
+
     for (def, val) in defines {
         if val != "1" {
             // So far, only processing boolean flags
@@ -65,15 +85,23 @@ fn main() {
 
     // FIXME: This list is currently maintained manually;
     let known_modules = &[
+        "at",
         "auto_init",
         "auto_init_random",
         "bluetil_ad",
         "core_msg",
+        "core_msg_bus",
+        "dhcpv6_client",
+        "dhcpv6_client_ia_na",
+        "dhcpv6_client_ia_pd",
         "gcoap",
         "gnrc",
         "gnrc_icmpv6",
+        "gnrc_icmpv6_echo",
+        "gnrc_ipv6_ext",
         "gnrc_ipv6_nib",
         "gnrc_netapi_callbacks",
+        "gnrc_netif_bus",
         "gnrc_nettype_ccn",
         "gnrc_nettype_custom",
         "gnrc_nettype_gomach",
@@ -86,24 +114,38 @@ fn main() {
         "gnrc_nettype_tcp",
         "gnrc_nettype_udp",
         "gnrc_pktbuf",
+        "gnrc_rpl",
+        "gnrc_sixlowpan_ctx",
         "gnrc_udp",
         "ipv6",
         "microbit",
+        "mtd",
+        "mtd_write_page",
         "nimble_host",
         "periph_adc",
         "periph_dac",
+        "periph_flashpage",
         "periph_gpio",
+        "periph_gpio_irq",
+        "periph_hwrng",
         "periph_i2c",
+        "periph_pm",
+        "periph_rtc",
         "periph_spi",
+        "periph_wdt",
         "prng_shaxprng",
+        "pm_layer",
         "pthread",
         "random",
         "saul",
         "shell",
         "sock",
+        "sock_async",
         "sock_aux_local",
+        "sock_dns",
         "sock_tcp",
         "sock_udp",
+        "tsrb",
         "udp",
         "vfs",
         "ws281x",
@@ -166,4 +208,28 @@ fn main() {
             println!("cargo:rustc-cfg=accessible_riot_sys_{rust_name}");
         }
     }
+
+    // Some constants depend on the board, and are only present in the bindings if the board
+    // defines them as plain numbers (eg. CLOCK_CORECLOCK, which is often an expression like
+    // `MHZ(64)`). There is no header to inspect for those in RIOTBASE, so the bindgen output is
+    // searched instead. Without access to it, the constants are treated as absent.
+    let emulate_accessible_bindgen = ["CLOCK_CORECLOCK"];
+
+    println!("cargo:rerun-if-env-changed=DEP_RIOT_SYS_BINDGEN_OUTPUT_FILE");
+    let bindgen_output = env::var("DEP_RIOT_SYS_BINDGEN_OUTPUT_FILE")
+        .ok()
+        .map(|file| {
+            println!("cargo:rerun-if-changed={file}");
+            std::fs::read_to_string(&file).expect("Failed to read riot-sys bindgen output")
+        });
+    for rust_name in emulate_accessible_bindgen {
+        let cfg_name = rust_name.to_lowercase();
+        println!("cargo:rustc-check-cfg=cfg(accessible_riot_sys_{cfg_name})");
+        if bindgen_output
+            .as_ref()
+            .is_some_and(|code| code.contains(&format!("pub const {rust_name}:")))
+        {
+            println!("cargo:rustc-cfg=accessible_riot_sys_{cfg_name}");
+        }
+    }
 }
@@ -0,0 +1,201 @@
+//! Access to modems through RIOT's [AT (Hayes) command driver](https://doc.riot-os.org/group__drivers__at.html)
+//!
+//! The [AtParser] wraps an `at_dev_t` on a UART. Commands can be sent as whole strings, or be
+//! assembled piecewise using [core::fmt::Write]:
+//!
+//! ```no_run
+//! # use core::fmt::Write;
+//! # fn example(at: &mut riot_wrappers::at::AtParser) -> Result<(), riot_wrappers::at::AtError> {
+//! # let (ssid, password) = ("", "");
+//! write!(at, "AT+CWJAP=\"{}\",\"{}\"", ssid, password).unwrap();
+//! at.send()?;
+//! let response = at.await_response("OK", 5000)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! This module is only available with the `with_at_parser` feature.
+
+use core::mem::MaybeUninit;
+
+use crate::error::{NegativeErrorExt, NumericError};
+
+/// Size of the buffer in which an [AtParser] assembles commands
+///
+/// One byte of this is used for the string's nul termination.
+pub const COMMAND_LEN: usize = 128;
+
+/// Maximum length of a line in an [AtResponse]
+pub const RESPONSE_LEN: usize = 128;
+
+/// Timeout for the echo of a sent command, in microseconds
+///
+/// This is only relevant if the driver expects devices to echo commands (which is the default
+/// unless `CONFIG_AT_SEND_SKIP_ECHO` is set).
+const ECHO_TIMEOUT: u32 = 1_000_000;
+
+/// Error type of [AtParser] operations
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AtError {
+    /// The assembled command does not fit in [COMMAND_LEN] (or can not be expressed as a C
+    /// string)
+    CommandTooLong,
+    /// The device responded with `ERROR`
+    ErrorResponse,
+    /// Any error reported by the AT driver (eg. `-ETIMEDOUT` or `-ENOBUFS`)
+    Driver(NumericError),
+}
+
+impl From<NumericError> for AtError {
+    fn from(e: NumericError) -> Self {
+        AtError::Driver(e)
+    }
+}
+
+/// A single line received from the device
+pub struct AtResponse {
+    buffer: [u8; RESPONSE_LEN],
+    len: usize,
+}
+
+impl AtResponse {
+    /// The line's content, without the line ending
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+
+    /// The line's content as text, if it is valid UTF-8
+    pub fn as_str(&self) -> Option<&str> {
+        core::str::from_utf8(self.as_bytes()).ok()
+    }
+}
+
+impl core::fmt::Debug for AtResponse {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self.as_str() {
+            Some(s) => write!(fmt, "AtResponse({:?})", s),
+            None => write!(fmt, "AtResponse({:?})", self.as_bytes()),
+        }
+    }
+}
+
+/// An AT device on a UART
+///
+/// As the UART's receive interrupt writes into the device and its buffers, all of them need to be
+/// available for the rest of the program's runtime, and are thus passed in as static references.
+pub struct AtParser {
+    dev: &'static mut riot_sys::at_dev_t,
+    command: heapless::Vec<u8, COMMAND_LEN>,
+}
+
+impl AtParser {
+    /// Initialize the UART and an AT device on it
+    ///
+    /// The `rx_buf` buffers incoming data until it is processed; the `rp_buf` is used by the
+    /// driver when parsing responses.
+    #[doc(alias = "at_dev_init")]
+    pub fn new(
+        dev: &'static mut MaybeUninit<riot_sys::at_dev_t>,
+        uart: riot_sys::uart_t,
+        baudrate: u32,
+        rx_buf: &'static mut [u8],
+        rp_buf: &'static mut [u8],
+    ) -> Result<Self, AtError> {
+        let init = riot_sys::at_dev_init_t {
+            uart,
+            baudrate,
+            rx_buf: rx_buf.as_mut_ptr() as _,
+            rx_buf_size: rx_buf.len() as _,
+            rp_buf: rp_buf.as_mut_ptr() as _,
+            rp_buf_size: rp_buf.len() as _,
+        };
+        // unsafe: C API; all memory is static, and the device is only used once this succeeded.
+        (unsafe { riot_sys::at_dev_init(dev.as_mut_ptr(), &init) }).negative_to_error()?;
+        Ok(AtParser {
+            // unsafe: Initialized by the successful C function
+            dev: unsafe { dev.assume_init_mut() },
+            command: heapless::Vec::new(),
+        })
+    }
+
+    /// Send a command to the device, replacing anything assembled through [core::fmt::Write]
+    ///
+    /// The command is terminated with the driver's end-of-line sequence (`\r\n` by default).
+    #[doc(alias = "at_send_cmd")]
+    pub fn send_cmd(&mut self, cmd: &str) -> Result<(), AtError> {
+        self.command.clear();
+        self.command
+            .extend_from_slice(cmd.as_bytes())
+            .map_err(|_| AtError::CommandTooLong)?;
+        self.send()
+    }
+
+    /// Send the command assembled through [core::fmt::Write], and start assembling a new one.
+    #[doc(alias = "at_send_cmd")]
+    pub fn send(&mut self) -> Result<(), AtError> {
+        let result = self.send_assembled();
+        self.command.clear();
+        result
+    }
+
+    fn send_assembled(&mut self) -> Result<(), AtError> {
+        if self.command.contains(&0) {
+            return Err(AtError::CommandTooLong);
+        }
+        self.command.push(0).map_err(|_| AtError::CommandTooLong)?;
+        // unsafe: C API on an initialized device, with a nul terminated string
+        (unsafe { riot_sys::at_send_cmd(self.dev, self.command.as_ptr() as _, ECHO_TIMEOUT) })
+            .negative_to_error()?;
+        Ok(())
+    }
+
+    /// Read lines from the device until one starts with `expected`, and return that line.
+    ///
+    /// Empty lines and lines not matching are skipped (as are unsolicited result codes, unless
+    /// they match). An `ERROR` line ends the search with [AtError::ErrorResponse].
+    ///
+    /// The timeout applies to each line individually.
+    #[doc(alias = "at_readline")]
+    pub fn await_response(
+        &mut self,
+        expected: &str,
+        timeout_ms: u32,
+    ) -> Result<AtResponse, AtError> {
+        let timeout = timeout_ms.saturating_mul(1000);
+        let mut response = AtResponse {
+            buffer: [0; RESPONSE_LEN],
+            len: 0,
+        };
+        loop {
+            // unsafe: C API on an initialized device, with a buffer whose length is passed along
+            let len = (unsafe {
+                riot_sys::at_readline(
+                    self.dev,
+                    response.buffer.as_mut_ptr() as _,
+                    response.buffer.len() as _,
+                    false,
+                    timeout,
+                )
+            })
+            .negative_to_error()?;
+            response.len = len as usize;
+
+            let line = response.as_bytes();
+            if line.starts_with(expected.as_bytes()) {
+                return Ok(response);
+            }
+            if line == b"ERROR" {
+                return Err(AtError::ErrorResponse);
+            }
+        }
+    }
+}
+
+impl core::fmt::Write for AtParser {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.command
+            .extend_from_slice(s.as_bytes())
+            .map_err(|_| core::fmt::Error)
+    }
+}
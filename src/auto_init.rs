@@ -47,9 +47,24 @@ unsafe impl Sync for AutoInitModule {}
 /// Run the function `$func` during auto initialization, with the priority giving the position in
 /// the initialization sequence.
 ///
+/// The function is a plain Rust `fn()`; the macro creates the `extern "C"` wrapper that RIOT
+/// calls. Lower priorities run earlier; RIOT's own modules use the `AUTO_INIT_PRIO_MOD_*` values,
+/// relative to which a driver can place itself (eg. to run after `ztimer` is initialized).
+///
+/// Auto initialization runs in the main thread before the application's main function starts.
+/// A panic there does not stop only a single thread, but the whole startup, so the function
+/// should report errors in other ways (eg. by storing them where main picks them up).
+///
 /// Note that the priority has to be a literal value. Supporting configured priorities would be
 /// possible with proc macros, but their complexity would be excessive as long as this is not
 /// needed.
+///
+/// ```no_run
+/// fn init_sensor() {
+///     // Configure a peripheral the application uses later
+/// }
+/// riot_wrappers::auto_init!(init_sensor, 9999);
+/// ```
 #[macro_export]
 macro_rules! auto_init {
     ( $func:ident, $priority:literal ) => {
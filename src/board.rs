@@ -0,0 +1,71 @@
+//! Information on the board and CPU the program is built for
+//!
+//! These are fixed at build time; the values are the names RIOT uses for the `BOARD` and `CPU`
+//! make variables.
+//!
+//! The core clock frequency (`core_clock_hz()`) is only available on some boards: `CLOCK_CORECLOCK`
+//! is defined by the board's peripheral configuration, often through expressions (like `MHZ(64)`)
+//! that do not make it into the Rust bindings.
+
+/// The identifier of the RIOT board the program is being built for (eg. `"nrf52840dk"`)
+///
+/// This is the same as [crate::BOARD].
+#[doc(alias = "RIOT_BOARD")]
+pub const fn name() -> &'static str {
+    crate::BOARD
+}
+
+/// The identifier of the CPU family the program is being built for (eg. `"nrf52"`)
+#[doc(alias = "RIOT_CPU")]
+pub const fn cpu() -> &'static str {
+    crate::build_string(riot_sys::RIOT_CPU)
+}
+
+/// The frequency of the CPU core clock in Hz
+///
+/// This is only available where the bindings expose `CLOCK_CORECLOCK` as a constant; otherwise,
+/// [BoardInfo::core_clock_hz] is `None`.
+#[cfg(accessible_riot_sys_clock_coreclock)]
+#[doc(alias = "CLOCK_CORECLOCK")]
+pub const fn core_clock_hz() -> u32 {
+    riot_sys::CLOCK_CORECLOCK as u32
+}
+
+/// Board and CPU names combined with the RIOT version, eg. for reporting them in a single log
+/// line
+///
+/// This displays as `board (cpu), RIOT version`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BoardInfo {
+    pub name: &'static str,
+    pub cpu: &'static str,
+    /// See [crate::version::riot_version()]
+    pub riot_version: &'static str,
+    /// The core clock frequency (see `core_clock_hz()`), where it is available
+    pub core_clock_hz: Option<u32>,
+}
+
+impl BoardInfo {
+    /// Information on the board the program is built for
+    pub const fn current() -> Self {
+        BoardInfo {
+            name: name(),
+            cpu: cpu(),
+            riot_version: crate::version::riot_version(),
+            #[cfg(accessible_riot_sys_clock_coreclock)]
+            core_clock_hz: Some(core_clock_hz()),
+            #[cfg(not(accessible_riot_sys_clock_coreclock))]
+            core_clock_hz: None,
+        }
+    }
+}
+
+impl core::fmt::Display for BoardInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{} ({}), RIOT {}",
+            self.name, self.cpu, self.riot_version
+        )
+    }
+}
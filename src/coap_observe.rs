@@ -0,0 +1,274 @@
+//! A minimal client for observing CoAP resources ([RFC 7641])
+//!
+//! Neither gcoap nor nanocoap keep client requests open for notifications, so this sends its
+//! requests on a [UdpSock] of its own. Messages are built and parsed by nanocoap, and viewed
+//! through a gcoap [PacketBuffer]. It only covers observation: a single GET with the Observe
+//! option, after which the server's notifications are received until the observation is
+//! cancelled.
+//!
+//! ```no_run
+//! # use core::pin::pin;
+//! # use riot_wrappers::coap_observe::ObserveClient;
+//! # use riot_wrappers::socket::{UdpEp, UdpSock};
+//! # let remote: UdpEp = unimplemented!();
+//! let sock = UdpSock::bind(&UdpEp::ipv6_any(), pin!(Default::default())).unwrap();
+//! let token = 0x1234abcdu32.to_be_bytes(); // Preferably random
+//! let mut observation = ObserveClient::subscribe(sock, remote, "sensors/temp", token).unwrap();
+//! let mut buf = [0; 128];
+//! while let Ok(notification) = observation.recv(&mut buf, None) {
+//!     riot_wrappers::println!("Current value: {:?}", notification.payload);
+//!     if notification.observe.is_none() {
+//!         // The server ended the observation
+//!         break;
+//!     }
+//! }
+//! ```
+//!
+//! [RFC 7641]: https://www.rfc-editor.org/rfc/rfc7641
+
+use core::mem::MaybeUninit;
+
+use riot_sys::coap_pkt_t;
+
+use crate::error::{NegativeErrorExt, NumericError};
+use crate::gcoap::PacketBuffer;
+use crate::socket::{UdpEp, UdpSock};
+
+/// Largest request that can be built (limiting the length of the path)
+const REQUEST_LEN: usize = 128;
+
+/// Observe option value that registers an observation
+const OBSERVE_REGISTER: u32 = 0;
+/// Observe option value that deregisters an observation
+const OBSERVE_DEREGISTER: u32 = 1;
+
+/// A response received through an [ObserveClient]
+#[derive(Debug)]
+pub struct Notification<'b> {
+    /// The response code (eg. `0x45` for 2.05 Content)
+    pub code: u8,
+    /// The value of the Observe option
+    ///
+    /// If this is absent, the server did not (or no longer does) add the client to the list of
+    /// observers, and no further notifications are to be expected.
+    pub observe: Option<u32>,
+    pub payload: &'b [u8],
+}
+
+/// An observation of a single resource
+pub struct ObserveClient<'a> {
+    sock: UdpSock<'a>,
+    remote: UdpEp,
+    path: heapless::String<REQUEST_LEN>,
+    token: [u8; 4],
+    /// Message ID of the latest request
+    message_id: u16,
+    /// Observe value of the freshest notification received so far
+    latest: Option<u32>,
+}
+
+impl<'a> ObserveClient<'a> {
+    /// Register an observation of the resource at `path` (eg. `"sensors/temp"`) on the remote
+    ///
+    /// The request is sent as a non-confirmable GET; if it or the first response is lost, no
+    /// notifications arrive, and the observation needs to be set up again. The token identifies
+    /// the observation's messages, and should be random.
+    pub fn subscribe(
+        sock: UdpSock<'a>,
+        remote: UdpEp,
+        path: &str,
+        token: [u8; 4],
+    ) -> Result<Self, NumericError> {
+        let mut client = ObserveClient {
+            sock,
+            remote,
+            path: path.try_into().map_err(|_| crate::error::EOVERFLOW)?,
+            token,
+            message_id: u16::from_be_bytes([token[0], token[1]]),
+            latest: None,
+        };
+        client.send_get(OBSERVE_REGISTER)?;
+        Ok(client)
+    }
+
+    /// Wait for the next notification, and return it with its payload in the buffer
+    ///
+    /// Messages from other endpoints are ignored, as are notifications that are older than the
+    /// freshest one received so far (see [RFC 7641 Section 3.4]; as the client does not keep
+    /// time, notifications that are more than 128 seconds apart are not considered fresh by
+    /// time). Confirmable notifications are acknowledged.
+    ///
+    /// Notifications from the remote with other tokens (eg. of an earlier observation that was
+    /// not cancelled successfully) are rejected with a Reset message, so that the server ends
+    /// those observations. If the server rejects the request with a Reset message, this fails
+    /// with `ECONNRESET`.
+    ///
+    /// Without timeout, this blocks until a notification arrives; otherwise the timeout applies
+    /// to each received datagram, and produces an `ETIMEDOUT` error.
+    ///
+    /// [RFC 7641 Section 3.4]: https://www.rfc-editor.org/rfc/rfc7641#section-3.4
+    pub fn recv<'b>(
+        &mut self,
+        buf: &'b mut [u8],
+        timeout: Option<core::time::Duration>,
+    ) -> Result<Notification<'b>, NumericError> {
+        loop {
+            let (len, remote) = self.sock.recv_from(buf, timeout)?;
+            if remote.ipv6_addr() != self.remote.ipv6_addr() || remote.port() != self.remote.port()
+            {
+                continue;
+            }
+
+            let mut pkt = MaybeUninit::<coap_pkt_t>::uninit();
+            // unsafe: C API parsing the received part of the buffer
+            if unsafe { riot_sys::coap_parse(pkt.as_mut_ptr(), buf.as_mut_ptr(), len as _) } < 0 {
+                continue;
+            }
+            // unsafe: Initialized by coap_parse on success
+            let pkt = unsafe { pkt.assume_init_mut() };
+            let pkt_ptr: *mut coap_pkt_t = pkt;
+            // unsafe: C API on the parsed packet
+            let (message_type, message_id, own_token) = unsafe {
+                let pkt_ptr = crate::inline_cast_mut(pkt_ptr);
+                let token = core::slice::from_raw_parts(
+                    riot_sys::inline::coap_get_token(pkt_ptr) as *const u8,
+                    riot_sys::inline::coap_get_token_len(pkt_ptr) as usize,
+                );
+                (
+                    riot_sys::inline::coap_get_type(pkt_ptr) as u32,
+                    riot_sys::inline::coap_get_id(pkt_ptr),
+                    token == self.token,
+                )
+            };
+            // unsafe: The packet was parsed from the buffer, which is not used until the view is
+            // gone
+            let message = unsafe { PacketBuffer::from_parsed(pkt, buf.as_mut_ptr(), len) };
+            let code = message.get_code_raw();
+
+            if message_type == riot_sys::COAP_TYPE_RST {
+                if message_id == self.message_id {
+                    return Err(NumericError::from_constant(riot_sys::ECONNRESET as _));
+                }
+                continue;
+            }
+            if code == riot_sys::COAP_CODE_EMPTY as u8 {
+                continue;
+            }
+            if !own_token {
+                self.send_empty(riot_sys::COAP_TYPE_RST, message_id)?;
+                continue;
+            }
+            if message_type == riot_sys::COAP_TYPE_CON {
+                self.send_empty(riot_sys::COAP_TYPE_ACK, message_id)?;
+            }
+
+            let observe = message.observe();
+            let payload = message.payload();
+            // unsafe: Both pointers are into the buffer; the position can be used once the view is
+            // gone.
+            let payload_start = unsafe { payload.as_ptr().offset_from(buf.as_ptr()) } as usize;
+            let payload_end = payload_start + payload.len();
+
+            if let Some(observe) = observe {
+                if self.latest.is_some_and(|latest| !is_fresh(latest, observe)) {
+                    continue;
+                }
+                self.latest = Some(observe);
+            }
+
+            return Ok(Notification {
+                code,
+                observe,
+                payload: &buf[payload_start..payload_end],
+            });
+        }
+    }
+
+    /// End the observation by sending a deregistering GET (Observe: 1)
+    ///
+    /// The server's response is not awaited. If the request is lost, the server may keep sending
+    /// notifications for a while, which are then rejected by the network stack as the socket is
+    /// closed.
+    pub fn cancel(mut self) -> Result<(), NumericError> {
+        self.send_get(OBSERVE_DEREGISTER)
+    }
+
+    #[doc(alias = "coap_build_hdr")]
+    fn send_get(&mut self, observe: u32) -> Result<(), NumericError> {
+        let mut buf = [0u8; REQUEST_LEN];
+        self.message_id = self.message_id.wrapping_add(1);
+
+        // unsafe: C API writing into the buffer, which has room for the header with the token
+        let header_len = unsafe {
+            riot_sys::coap_build_hdr(
+                buf.as_mut_ptr() as *mut _,
+                riot_sys::COAP_TYPE_NON as _,
+                self.token.as_ptr() as _,
+                self.token.len() as _,
+                riot_sys::COAP_METHOD_GET as _,
+                self.message_id,
+            )
+        }
+        .negative_to_error()? as usize;
+
+        let mut pkt = MaybeUninit::<coap_pkt_t>::uninit();
+        // unsafe: C API initializing the packet for the buffer
+        unsafe {
+            riot_sys::coap_pkt_init(
+                pkt.as_mut_ptr(),
+                buf.as_mut_ptr(),
+                buf.len() as _,
+                header_len as _,
+            )
+        };
+        // unsafe: Initialized by coap_pkt_init
+        let pkt = unsafe { pkt.assume_init_mut() };
+
+        // unsafe: C API adding options in order of their numbers to the initialized packet
+        let len = unsafe {
+            riot_sys::coap_opt_add_uint(pkt, riot_sys::COAP_OPT_OBSERVE as _, observe)
+                .negative_to_error()?;
+            riot_sys::coap_opt_add_chars(
+                pkt,
+                riot_sys::COAP_OPT_URI_PATH as _,
+                self.path.as_ptr() as _,
+                self.path.len() as _,
+                b'/' as _,
+            )
+            .negative_to_error()?;
+            riot_sys::coap_opt_finish(pkt, riot_sys::COAP_OPT_FINISH_NONE as _)
+                .negative_to_error()?
+        } as usize;
+
+        self.sock.send_to(&buf[..len], &self.remote)?;
+        Ok(())
+    }
+
+    /// Send an empty message (an ACK or RST) in response to the given message ID
+    fn send_empty(&mut self, message_type: u32, message_id: u16) -> Result<(), NumericError> {
+        let mut buf = [0u8; 4];
+        // unsafe: C API writing a header without token into a buffer large enough for it
+        unsafe {
+            riot_sys::coap_build_hdr(
+                buf.as_mut_ptr() as *mut _,
+                message_type as _,
+                core::ptr::null(),
+                0,
+                riot_sys::COAP_CODE_EMPTY as _,
+                message_id,
+            )
+        }
+        .negative_to_error()?;
+        self.sock.send_to(&buf, &self.remote)?;
+        Ok(())
+    }
+}
+
+/// Whether a notification with Observe value `new` is fresher than one with `old`
+///
+/// This is the sequence number criterion of RFC 7641 Section 3.4, which handles the 24-bit
+/// wraparound of the values.
+fn is_fresh(old: u32, new: u32) -> bool {
+    const HALF: u32 = 1 << 23;
+    (old < new && new - old < HALF) || (old > new && old - new > HALF)
+}
@@ -0,0 +1,225 @@
+//! Running RIOT's [DHCPv6 client](https://doc.riot-os.org/group__net__dhcpv6__client.html)
+//!
+//! The client is driven by an event queue. [Dhcp6Client::new] creates a thread for that queue on
+//! a stack provided by the application, and starts the client on one network interface. Leases are
+//! then applied by the network stack itself: addresses obtained through IA_NA are added to the
+//! interface, and prefixes obtained through IA_PD are configured on the downstream interfaces (and
+//! announced there if the node is a router).
+//!
+//! RIOT does not report lease changes to applications. Addresses that get configured in the
+//! process can be observed through the interface's [address list](crate::gnrc::Netif::ipv6_addrs),
+//! or (with `gnrc_netif_bus`) through [`gnrc::netif_event`](crate::gnrc::netif_event). Delegated
+//! prefixes are detected by the client thread as they appear in the NIB's prefix list, and
+//! reported through [Dhcp6Client::on_prefix_delegated].
+//!
+//! When the `auto_init_dhcpv6_client` module is active, RIOT already runs the client in a thread
+//! of its own, and it must not be started again; the requests can still be made.
+//!
+//! This module is only available with the `with_dhcpv6` feature.
+//!
+//! ```no_run
+//! # use riot_wrappers::dhcpv6::Dhcp6Client;
+//! # use riot_wrappers::thread::Priority;
+//! # let netif: riot_wrappers::gnrc::Netif = unimplemented!();
+//! static mut STACK: [u8; 1024] = [0; 1024];
+//! let stack = unsafe { &mut *core::ptr::addr_of_mut!(STACK) };
+//! let client = Dhcp6Client::new(&netif, stack, Priority::new(Priority::MAIN.get() - 1)).unwrap();
+//! client.request_ia_pd(64).unwrap();
+//! ```
+
+use core::sync::atomic::{AtomicBool, AtomicU16, Ordering};
+
+use crate::error::{NegativeErrorExt, NumericError};
+use crate::gnrc::Netif;
+use crate::thread::{KernelPID, Priority};
+
+/// Set when a client was started; RIOT's client is a singleton.
+static STARTED: AtomicBool = AtomicBool::new(false);
+/// Interface the client thread is started on
+static NETIF: AtomicU16 = AtomicU16::new(0);
+
+/// Entry point of the client thread
+fn run() {
+    let mut queue = core::mem::MaybeUninit::<riot_sys::event_queue_t>::uninit();
+    let queue = queue.as_mut_ptr();
+    // unsafe: C APIs. The queue lives on this thread's stack, and as the loop below never ends,
+    // it stays valid for as long as the client uses it.
+    unsafe {
+        riot_sys::inline::event_queue_init(crate::inline_cast_mut(queue));
+        riot_sys::dhcpv6_client_init(queue, NETIF.load(Ordering::Relaxed));
+        riot_sys::dhcpv6_client_start();
+    }
+
+    // Like event_loop, but taking the chance to look for prefixes delegated during each event
+    loop {
+        // unsafe: C API on the initialized queue
+        let event = unsafe { riot_sys::event_wait_multi(queue, 1) };
+
+        #[cfg(all(riot_module_dhcpv6_client_ia_pd, riot_module_gnrc_ipv6_nib))]
+        let before = prefix_tracking::Prefixes::current();
+
+        // unsafe: Events come from the client, which only queues valid events
+        if let Some(handler) = unsafe { (*event).handler } {
+            // unsafe: Calling the handler as event_loop does
+            unsafe { handler(event) };
+        }
+
+        #[cfg(all(riot_module_dhcpv6_client_ia_pd, riot_module_gnrc_ipv6_nib))]
+        before.report_added();
+    }
+}
+
+/// Handle to the running DHCPv6 client
+///
+/// As there is only one client in RIOT, at most one of these can be obtained, and the client runs
+/// until the system stops.
+#[derive(Debug)]
+pub struct Dhcp6Client {
+    netif: u16,
+    pid: KernelPID,
+}
+
+impl Dhcp6Client {
+    /// Start the client on the given interface in a new thread, running on the given stack
+    ///
+    /// This fails with `EALREADY` if a client was already started, and returns the thread
+    /// creation error otherwise.
+    #[doc(alias = "dhcpv6_client_init")]
+    #[doc(alias = "dhcpv6_client_start")]
+    pub fn new(
+        netif: &Netif,
+        stack: &'static mut [u8],
+        priority: Priority,
+    ) -> Result<Self, NumericError> {
+        if STARTED.swap(true, Ordering::AcqRel) {
+            return Err(NumericError::from_constant(riot_sys::EALREADY as _));
+        }
+        let netif = netif.pid().0 as u16;
+        NETIF.store(netif, Ordering::Relaxed);
+
+        let thread = crate::thread::spawn_fn(stack, run, c"dhcpv6", priority, 0).map_err(|e| {
+            STARTED.store(false, Ordering::Release);
+            // Thread creation reports negative errno values
+            e.negative_to_error()
+                .expect_err("Thread creation failed with a positive value")
+        })?;
+
+        Ok(Dhcp6Client {
+            netif,
+            pid: thread.pid(),
+        })
+    }
+
+    /// The thread the client runs in
+    pub fn pid(&self) -> KernelPID {
+        self.pid
+    }
+
+    /// Request an address for the interface through IA_NA (identity association for
+    /// non-temporary addresses)
+    ///
+    /// This is only available with the `dhcpv6_client_ia_na` module.
+    #[cfg(riot_module_dhcpv6_client_ia_na)]
+    #[doc(alias = "dhcpv6_client_req_ia_na")]
+    pub fn request_ia_na(&self) -> Result<(), NumericError> {
+        // unsafe: C API, which only queues the request for the client
+        unsafe { riot_sys::dhcpv6_client_req_ia_na(self.netif.into()) }
+            .negative_to_error()
+            .map(|_| ())
+    }
+
+    /// Request delegation of a prefix of the given length through IA_PD (identity association
+    /// for prefix delegation)
+    ///
+    /// This is only available with the `dhcpv6_client_ia_pd` module.
+    #[cfg(riot_module_dhcpv6_client_ia_pd)]
+    #[doc(alias = "dhcpv6_client_req_ia_pd")]
+    pub fn request_ia_pd(&self, prefix_len: u8) -> Result<(), NumericError> {
+        // unsafe: C API, which only queues the request for the client
+        unsafe { riot_sys::dhcpv6_client_req_ia_pd(self.netif.into(), prefix_len.into()) }
+            .negative_to_error()
+            .map(|_| ())
+    }
+
+    /// Call the function whenever a prefix is delegated, with the prefix and its length
+    ///
+    /// RIOT does not report leases, so this is detected by the client thread, which compares the
+    /// NIB's prefix list before and after handling each of its events: Prefixes (or prefix
+    /// lengths) that were added in the course of an event are reported. Renewals that do not
+    /// change the prefix are not reported, nor is the expiry of a prefix. The function is called
+    /// in the client thread, and replaces any function that was set before.
+    ///
+    /// This is only available with the `dhcpv6_client_ia_pd` and `gnrc_ipv6_nib` modules.
+    #[cfg(all(riot_module_dhcpv6_client_ia_pd, riot_module_gnrc_ipv6_nib))]
+    pub fn on_prefix_delegated<F>(&self, callback: &'static mut F)
+    where
+        F: FnMut(&crate::gnrc::ipv6::Address, u8) + Send,
+    {
+        *prefix_tracking::CALLBACK.lock() = Some(callback);
+    }
+
+    /// Whether the client was built with support for IA_NA
+    ///
+    /// This is a property of the build (the `dhcpv6_client_ia_na` module), not of any lease.
+    pub const fn ia_na_supported() -> bool {
+        cfg!(riot_module_dhcpv6_client_ia_na)
+    }
+
+    /// Whether the client was built with support for IA_PD
+    ///
+    /// This is a property of the build (the `dhcpv6_client_ia_pd` module), not of any lease; see
+    /// [Self::on_prefix_delegated] for learning of delegated prefixes.
+    pub const fn ia_pd_supported() -> bool {
+        cfg!(riot_module_dhcpv6_client_ia_pd)
+    }
+}
+
+#[cfg(all(riot_module_dhcpv6_client_ia_pd, riot_module_gnrc_ipv6_nib))]
+mod prefix_tracking {
+    use crate::gnrc::ipv6::Address;
+    use crate::mutex::Mutex;
+
+    type Callback = dyn FnMut(&Address, u8) + Send;
+
+    pub(super) static CALLBACK: Mutex<Option<&'static mut Callback>> = Mutex::new(None);
+
+    /// Number of prefixes that are remembered; this is the default size of the NIB's off-link
+    /// entry table, from which prefixes are taken. With more prefixes, some may be reported
+    /// without having changed.
+    const TRACKED: usize = 8;
+
+    /// Snapshot of the NIB's prefix list
+    pub(super) struct Prefixes(heapless::Vec<([u8; 16], u8), TRACKED>);
+
+    impl Prefixes {
+        pub(super) fn current() -> Self {
+            let mut prefixes = heapless::Vec::new();
+            scan(|prefix, len| {
+                let _ = prefixes.push((*prefix.raw(), len));
+            });
+            Prefixes(prefixes)
+        }
+
+        /// Report any prefix that is present now but was not in the snapshot
+        pub(super) fn report_added(self) {
+            let mut callback = CALLBACK.lock();
+            let Some(callback) = callback.as_mut() else {
+                return;
+            };
+            scan(|prefix, len| {
+                if !self.0.contains(&(*prefix.raw(), len)) {
+                    callback(prefix, len);
+                }
+            });
+        }
+    }
+
+    fn scan(mut on_prefix: impl FnMut(&Address, u8)) {
+        let mut state = core::ptr::null_mut();
+        let mut entry = riot_sys::gnrc_ipv6_nib_pl_t::default();
+        // unsafe: C API, iterating over all interfaces' prefixes into a local entry
+        while unsafe { riot_sys::gnrc_ipv6_nib_pl_iter(0, &mut state, &mut entry) } {
+            on_prefix(Address::from_ref(&entry.pfx), entry.pfx_len);
+        }
+    }
+}
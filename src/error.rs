@@ -9,6 +9,12 @@
 
 use core::convert::TryInto;
 
+/// Conversion of C style return values (negative error numbers, or non-negative results) into a
+/// [Result]
+///
+/// This is implemented for all numeric types that can be compared to zero, in particular for
+/// `c_int`, `isize` (`ssize_t`) and `i64` alike; the successful result is returned in the original
+/// type.
 pub trait NegativeErrorExt {
     type Out;
 
@@ -23,7 +29,7 @@ pub trait NegativeErrorExt {
 /// represent `Result<positive_usize, NumericError>` as just the isize it originally was. For the
 /// time being, this works well enough, and performance evaluation can later be done against a
 /// manually implemented newtype around isize that'd be used to represent the Result.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(PartialEq, Eq)]
 pub struct NumericError {
     number: isize,
 }
@@ -42,7 +48,7 @@ impl NumericError {
     /// # use riot_wrappers::error::NumericError;
     /// # use riot_wrappers::stdio::println;
     /// let err = NumericError::from_constant(riot_sys::ENOTSUP as _);
-    /// println!("{:?}", err); // NumericError { number: -61 }
+    /// println!("{:?}", err); // NumericError { number: -61, errno: ENOTSUP }
     /// # 0
     /// # }
     /// ```
@@ -63,6 +69,14 @@ impl NumericError {
         self.number
     }
 
+    /// Classify the error as one of the well-known [Errno] values
+    ///
+    /// This returns None for numbers not (yet) listed in [Errno]; those are still available
+    /// through [.number()](Self::number).
+    pub fn errno(&self) -> Option<Errno> {
+        Errno::from_number(self.number)
+    }
+
     /// Convert the error into an [nb::Error] that is [nb::Error::WouldBlock] if the error is
     /// `-EAGAIN`, and an actual error otherwise.
     pub fn again_is_wouldblock(self) -> nb::Error<Self> {
@@ -73,13 +87,52 @@ impl NumericError {
     }
 }
 
-// Would be nice, but there's no strerror
-//
-// impl core::fmt::Display for NumericError {
-//     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
-//         write!(f, "Error {} ({})", self.number(), ...)
-//     }
-// }
+impl core::fmt::Debug for NumericError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let mut s = f.debug_struct("NumericError");
+        s.field("number", &self.number);
+        if let Some(errno) = self.errno() {
+            s.field("errno", &errno);
+        }
+        s.finish()
+    }
+}
+
+// There's no strerror, but the errno names and descriptions of the more common errors are known
+impl core::fmt::Display for NumericError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self.errno() {
+            Some(errno) => write!(
+                f,
+                "Error {} ({}: {})",
+                self.number(),
+                errno.name(),
+                errno.description()
+            ),
+            None => write!(f, "Error {}", self.number()),
+        }
+    }
+}
+
+impl core::error::Error for NumericError {}
+
+#[cfg(feature = "with_defmt")]
+impl defmt::Format for NumericError {
+    fn format(&self, f: defmt::Formatter) {
+        match self.errno() {
+            Some(errno) => defmt::write!(f, "Error {=isize} ({=str})", self.number(), errno.name()),
+            None => defmt::write!(f, "Error {=isize}", self.number()),
+        }
+    }
+}
+
+#[cfg(feature = "with_embedded_nal_async")]
+impl embedded_io_async::Error for NumericError {
+    fn kind(&self) -> embedded_io_async::ErrorKind {
+        // FIXME there are some that do make sense here
+        embedded_io_async::ErrorKind::Other
+    }
+}
 
 impl<T> NegativeErrorExt for T
 where
@@ -111,3 +164,125 @@ E!(EINVAL);
 E!(ENOMEM);
 E!(ENOSPC);
 E!(EOVERFLOW);
+
+macro_rules! errnos {
+    ($($e:ident: $description:literal,)*) => {
+        /// Commonly used POSIX error values, as RIOT defines them
+        ///
+        /// These exist to make matching on errors readable (and independent of the numbers a
+        /// particular platform's libc assigns). The list is populated on demand; errors not listed
+        /// here are still available as [NumericError::number].
+        ///
+        /// Errors are obtained through [NumericError::errno], and can be converted back into a
+        /// [NumericError].
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum Errno {
+            $(
+                #[doc = $description]
+                $e,
+            )*
+        }
+
+        impl Errno {
+            fn from_number(number: isize) -> Option<Self> {
+                let positive: u32 = number.checked_neg()?.try_into().ok()?;
+                match positive {
+                    $(riot_sys::$e => Some(Errno::$e),)*
+                    _ => None,
+                }
+            }
+
+            /// The positive constant of the error (eg. `riot_sys::ENOMEM`)
+            pub fn constant(self) -> u32 {
+                match self {
+                    $(Errno::$e => riot_sys::$e,)*
+                }
+            }
+
+            /// The C name of the error (eg. `"ENOMEM"`)
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Errno::$e => stringify!($e),)*
+                }
+            }
+
+            /// A short human readable description of the error
+            pub fn description(self) -> &'static str {
+                match self {
+                    $(Errno::$e => $description,)*
+                }
+            }
+        }
+    };
+}
+
+// EWOULDBLOCK and EOPNOTSUPP are left out, as they share their numbers with EAGAIN and ENOTSUP
+// on some platforms.
+errnos! {
+    EPERM: "Operation not permitted",
+    ENOENT: "No such file or directory",
+    EINTR: "Interrupted system call",
+    EIO: "I/O error",
+    ENXIO: "No such device or address",
+    EBADF: "Bad file number",
+    EAGAIN: "Resource temporarily unavailable",
+    ENOMEM: "Not enough memory",
+    EACCES: "Permission denied",
+    EFAULT: "Bad address",
+    EBUSY: "Device or resource busy",
+    EEXIST: "File exists",
+    ENODEV: "No such device",
+    ENOTDIR: "Not a directory",
+    EISDIR: "Is a directory",
+    EINVAL: "Invalid argument",
+    ENOSPC: "No space left on device",
+    ERANGE: "Result out of range",
+    ENOSYS: "Function not implemented",
+    ENOTSUP: "Not supported",
+    EOVERFLOW: "Value too large for defined data type",
+    EBADMSG: "Bad message",
+    ECANCELED: "Operation canceled",
+    EPROTO: "Protocol error",
+    EMSGSIZE: "Message too long",
+    ENOBUFS: "No buffer space available",
+    EAFNOSUPPORT: "Address family not supported",
+    EADDRINUSE: "Address already in use",
+    EADDRNOTAVAIL: "Address not available",
+    ENETDOWN: "Network is down",
+    ENETUNREACH: "Network is unreachable",
+    EHOSTUNREACH: "Host is unreachable",
+    ECONNABORTED: "Connection aborted",
+    ECONNRESET: "Connection reset",
+    ECONNREFUSED: "Connection refused",
+    EISCONN: "Socket is already connected",
+    ENOTCONN: "Socket is not connected",
+    ETIMEDOUT: "Timed out",
+    EALREADY: "Operation already in progress",
+    EINPROGRESS: "Operation in progress",
+}
+
+impl From<Errno> for NumericError {
+    fn from(errno: Errno) -> Self {
+        NumericError::from_constant(errno.constant() as _)
+    }
+}
+
+impl PartialEq<Errno> for NumericError {
+    fn eq(&self, other: &Errno) -> bool {
+        self.errno() == Some(*other)
+    }
+}
+
+impl core::fmt::Display for Errno {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} ({})", self.description(), self.name())
+    }
+}
+
+#[cfg(feature = "with_defmt")]
+impl defmt::Format for Errno {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str} ({=str})", self.description(), self.name())
+    }
+}
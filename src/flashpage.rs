@@ -0,0 +1,113 @@
+//! Access to the MCU's internal flash through [periph_flashpage](https://doc.riot-os.org/group__drivers__periph__flashpage.html)
+//!
+//! Flash is organized in [numof()] pages of [size()] bytes each. Before a page can be written to,
+//! it needs to be erased. Note that the same flash also holds the running firmware: the
+//! application is responsible for only touching pages that are not used otherwise (typically the
+//! last ones, with the linker script set up accordingly).
+//!
+//! All checks necessary to avoid faults in the underlying C functions (which only assert on
+//! their inputs) are performed here, and violations are reported as `EINVAL` or `EOVERFLOW`.
+
+use crate::error::NumericError;
+
+/// Number of flash pages
+pub const fn numof() -> usize {
+    riot_sys::FLASHPAGE_NUMOF as _
+}
+
+/// Size of a single flash page, in bytes
+pub const fn size() -> usize {
+    riot_sys::FLASHPAGE_SIZE as _
+}
+
+/// Granularity of writes: the length of data written to a page needs to be a multiple of this
+pub const fn write_block_size() -> usize {
+    riot_sys::FLASHPAGE_WRITE_BLOCK_SIZE as _
+}
+
+fn check_page(page: usize) -> Result<(), NumericError> {
+    if page >= numof() {
+        return Err(crate::error::EINVAL);
+    }
+    Ok(())
+}
+
+fn page_addr(page: usize) -> *mut u8 {
+    // unsafe: Side effect free C function on a page that was checked to exist
+    unsafe { riot_sys::inline::flashpage_addr(page as _) as *mut u8 }
+}
+
+/// Read the start of a flash page into the buffer
+///
+/// The buffer may be shorter than a page; it may not be longer.
+#[doc(alias = "flashpage_read")]
+pub fn read(page: usize, buf: &mut [u8]) -> Result<(), NumericError> {
+    read_at(page, 0, buf)
+}
+
+/// Read from a flash page, starting `offset` bytes into the page
+///
+/// The read range needs to be within the page.
+pub fn read_at(page: usize, offset: usize, buf: &mut [u8]) -> Result<(), NumericError> {
+    check_page(page)?;
+    if offset > size() || buf.len() > size() - offset {
+        return Err(crate::error::EOVERFLOW);
+    }
+    // unsafe: Internal flash is memory mapped, and the range is within the page. (This is what
+    // flashpage_read does, but that always reads a whole page).
+    unsafe {
+        core::ptr::copy_nonoverlapping(page_addr(page).add(offset), buf.as_mut_ptr(), buf.len())
+    };
+    Ok(())
+}
+
+/// Write data to the start of a flash page
+///
+/// The page needs to be [erase]d before; as flash bits can only be cleared by writing, writing
+/// over data that was written before produces garbage.
+///
+/// The data's length needs to be a multiple of [write_block_size()], its address in memory needs
+/// to be aligned to `FLASHPAGE_WRITE_BLOCK_ALIGNMENT`, and it may not be longer than a page.
+#[doc(alias = "flashpage_write")]
+pub fn write(page: usize, data: &[u8]) -> Result<(), NumericError> {
+    write_at(page, 0, data)
+}
+
+/// Write data to a flash page, starting `offset` bytes into the page
+///
+/// The same requirements as for [write] apply; additionally, the offset needs to be aligned to
+/// `FLASHPAGE_WRITE_BLOCK_ALIGNMENT`, and the written range needs to be within the page.
+#[doc(alias = "flashpage_write")]
+pub fn write_at(page: usize, offset: usize, data: &[u8]) -> Result<(), NumericError> {
+    const ALIGNMENT: usize = riot_sys::FLASHPAGE_WRITE_BLOCK_ALIGNMENT as _;
+
+    check_page(page)?;
+    if offset > size() || data.len() > size() - offset {
+        return Err(crate::error::EOVERFLOW);
+    }
+    if data.len() % write_block_size() != 0
+        || offset % ALIGNMENT != 0
+        || data.as_ptr() as usize % ALIGNMENT != 0
+    {
+        return Err(crate::error::EINVAL);
+    }
+    // unsafe: C API; the target is aligned as pages start aligned and the offset was checked, and
+    // the data was checked to meet the requirements.
+    unsafe {
+        riot_sys::flashpage_write(
+            page_addr(page).add(offset) as _,
+            data.as_ptr() as _,
+            data.len() as _,
+        )
+    };
+    Ok(())
+}
+
+/// Erase a flash page, setting all its bytes to their erased value (typically 0xff)
+#[doc(alias = "flashpage_erase")]
+pub fn erase(page: usize) -> Result<(), NumericError> {
+    check_page(page)?;
+    // unsafe: C API on an existing page
+    unsafe { riot_sys::flashpage_erase(page as _) };
+    Ok(())
+}
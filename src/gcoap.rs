@@ -355,6 +355,17 @@ pub struct PacketBuffer<'b> {
 }
 
 impl<'b> PacketBuffer<'b> {
+    /// View a message that was parsed into `pkt` (eg. through `coap_parse`) from `len` bytes at
+    /// `buf`
+    ///
+    /// # Safety
+    ///
+    /// The packet needs to describe the buffer, and the buffer needs to stay valid (and not be
+    /// used otherwise) for as long as the PacketBuffer is.
+    pub(crate) unsafe fn from_parsed(pkt: &'b mut coap_pkt_t, buf: *mut u8, len: usize) -> Self {
+        PacketBuffer { pkt, buf, len }
+    }
+
     /// Wrapper for coap_get_code_raw
     pub fn get_code_raw(&self) -> u8 {
         (unsafe { riot_sys::coap_get_code_raw(crate::inline_cast_ref(self.pkt)) }) as u8
@@ -453,6 +464,73 @@ impl<'b> PacketBuffer<'b> {
         .map(|_| ())
     }
 
+    /// Read the first option of the given number as an unsigned integer
+    ///
+    /// This returns None if the option is absent, or does not fit a u32.
+    #[doc(alias = "coap_opt_get_uint")]
+    pub fn opt_get_uint(&self, optnum: u16) -> Option<u32> {
+        let mut value = 0;
+        // unsafe: C API on a parsed packet (which it does not actually modify)
+        let pkt = &*self.pkt as *const _ as *mut _;
+        match unsafe { riot_sys::coap_opt_get_uint(pkt, optnum, &mut value) } {
+            0 => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Join all options of the given number into the buffer, separated (and started) by the
+    /// separator, and return the resulting string
+    ///
+    /// This fails with `ENOSPC` if the buffer is too short, or `EINVAL` if the result is not
+    /// valid UTF-8.
+    #[doc(alias = "coap_opt_get_string")]
+    pub fn opt_get_string<'a>(
+        &self,
+        optnum: u16,
+        buf: &'a mut [u8],
+        separator: u8,
+    ) -> Result<&'a str, crate::error::NumericError> {
+        // unsafe: C API on a parsed packet (which it does not actually modify), writing into a
+        // buffer of the indicated size
+        unsafe {
+            riot_sys::coap_opt_get_string(
+                &*self.pkt as *const _ as *mut _,
+                optnum,
+                buf.as_mut_ptr(),
+                buf.len() as _,
+                separator as _,
+            )
+        }
+        .negative_to_error()?;
+        core::ffi::CStr::from_bytes_until_nul(buf)
+            .map_err(|_| crate::error::ENOSPC)?
+            .to_str()
+            .map_err(|_| crate::error::EINVAL)
+    }
+
+    /// The request's full path, built from all Uri-Path options (eg. `/sensors/temp`)
+    ///
+    /// See [Self::opt_get_string] for error conditions.
+    #[doc(alias = "coap_get_uri_path")]
+    pub fn uri_path<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, crate::error::NumericError> {
+        self.opt_get_string(riot_sys::COAP_OPT_URI_PATH as _, buf, b'/')
+    }
+
+    /// The message's Content-Format, if the option is present
+    #[doc(alias = "coap_get_content_type")]
+    pub fn content_format(&self) -> Option<ContentFormat> {
+        self.opt_get_uint(riot_sys::COAP_OPT_CONTENT_FORMAT as _)
+            .and_then(|v| u16::try_from(v).ok())
+            .map(ContentFormat::from)
+    }
+
+    /// The value of the message's Observe option, if present
+    ///
+    /// In a request, the value 0 registers for observation, and 1 deregisters.
+    pub fn observe(&self) -> Option<u32> {
+        self.opt_get_uint(riot_sys::COAP_OPT_OBSERVE as _)
+    }
+
     pub fn opt_iter<'a>(&'a self) -> PacketBufferOptIter<'a, 'b> {
         PacketBufferOptIter {
             buffer: self,
@@ -570,3 +648,1007 @@ impl<'a, 'b> Iterator for PacketBufferOptIterMut<'a, 'b> {
         }
     }
 }
+
+/// A CoAP resource that can be served through a [Server]
+///
+/// Unlike a [Handler], this does not need to deal with the message buffer directly: The request
+/// is presented in parsed form, and the response is assembled from the pieces set on it.
+pub trait Resource {
+    /// Process a request, set up the response's details, and return the response code.
+    ///
+    /// A code set through [Response::set_code] takes precedence over the returned one. If this
+    /// returns an error, a 5.00 Internal Server Error response is sent instead.
+    fn handle(
+        &mut self,
+        request: &Request<'_, '_>,
+        response: &mut Response<'_>,
+    ) -> Result<u8, crate::error::NumericError>;
+}
+
+/// Any suitable closure or function can serve as a resource, for handlers that need no other
+/// state than what they capture.
+impl<F> Resource for F
+where
+    F: FnMut(&Request<'_, '_>, &mut Response<'_>) -> Result<u8, crate::error::NumericError>,
+{
+    fn handle(
+        &mut self,
+        request: &Request<'_, '_>,
+        response: &mut Response<'_>,
+    ) -> Result<u8, crate::error::NumericError> {
+        self(request, response)
+    }
+}
+
+/// View of an incoming request as presented to a [Resource]
+pub struct Request<'a, 'b> {
+    buffer: &'a PacketBuffer<'b>,
+}
+
+impl<'a, 'b> Request<'a, 'b> {
+    /// The request's method code (eg. 1 for GET)
+    pub fn method(&self) -> u8 {
+        self.buffer.get_code_raw()
+    }
+
+    /// The Uri-Path options of the request, in order
+    pub fn path_segments(&self) -> impl Iterator<Item = &'a [u8]> {
+        self.buffer
+            .opt_iter()
+            .filter(|(num, _)| *num == riot_sys::COAP_OPT_URI_PATH as u16)
+            .map(|(_, value)| value)
+    }
+
+    /// All options of the request, as number and value
+    pub fn options(&self) -> PacketBufferOptIter<'a, 'b> {
+        self.buffer.opt_iter()
+    }
+
+    /// The request's full path; see [PacketBuffer::uri_path]
+    pub fn uri_path<'s>(&self, buf: &'s mut [u8]) -> Result<&'s str, crate::error::NumericError> {
+        self.buffer.uri_path(buf)
+    }
+
+    pub fn content_format(&self) -> Option<ContentFormat> {
+        self.buffer.content_format()
+    }
+
+    /// The raw value of the Block2 option, if present
+    pub fn block2(&self) -> Option<u32> {
+        self.buffer.opt_get_uint(riot_sys::COAP_OPT_BLOCK2 as _)
+    }
+
+    /// The value of the Observe option, if present (0 for registration, 1 for deregistration)
+    pub fn observe(&self) -> Option<u32> {
+        self.buffer.observe()
+    }
+
+    pub fn payload(&self) -> &'a [u8] {
+        self.buffer.payload()
+    }
+}
+
+/// A CoAP Content-Format, with names for the commonly used ones
+///
+/// All numeric values can be represented; those without a name are [ContentFormat::Other].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContentFormat {
+    /// `text/plain; charset=utf-8`
+    TextPlain,
+    /// `application/link-format`
+    LinkFormat,
+    /// `application/xml`
+    Xml,
+    /// `application/octet-stream`
+    OctetStream,
+    /// `application/json`
+    Json,
+    /// `application/cbor`
+    Cbor,
+    /// `application/senml+json`
+    SenmlJson,
+    /// `application/senml+cbor`
+    SenmlCbor,
+    Other(u16),
+}
+
+impl From<u16> for ContentFormat {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => ContentFormat::TextPlain,
+            40 => ContentFormat::LinkFormat,
+            41 => ContentFormat::Xml,
+            42 => ContentFormat::OctetStream,
+            50 => ContentFormat::Json,
+            60 => ContentFormat::Cbor,
+            110 => ContentFormat::SenmlJson,
+            112 => ContentFormat::SenmlCbor,
+            other => ContentFormat::Other(other),
+        }
+    }
+}
+
+impl From<ContentFormat> for u16 {
+    fn from(format: ContentFormat) -> Self {
+        match format {
+            ContentFormat::TextPlain => 0,
+            ContentFormat::LinkFormat => 40,
+            ContentFormat::Xml => 41,
+            ContentFormat::OctetStream => 42,
+            ContentFormat::Json => 50,
+            ContentFormat::Cbor => 60,
+            ContentFormat::SenmlJson => 110,
+            ContentFormat::SenmlCbor => 112,
+            ContentFormat::Other(other) => other,
+        }
+    }
+}
+
+/// Response under construction by a [Resource]
+///
+/// The payload is staged in the unused part of the message buffer behind the request, and moved
+/// into place after the request has been processed and the response's options are written.
+pub struct Response<'a> {
+    code: Option<u8>,
+    content_format: Option<u16>,
+    block2: Option<u32>,
+    staging: &'a mut [u8],
+    payload_len: usize,
+}
+
+impl<'a> Response<'a> {
+    /// Set the response code (eg. `0x84` for 4.04 Not Found)
+    ///
+    /// This takes precedence over the code returned by the [Resource].
+    pub fn set_code(&mut self, code: u8) {
+        self.code = Some(code);
+    }
+
+    /// Set the Content-Format option of the response
+    pub fn set_content_format(&mut self, format: impl Into<u16>) {
+        self.content_format = Some(format.into());
+    }
+
+    /// Set the response payload
+    ///
+    /// This fails if the payload exceeds the space that is available for it.
+    pub fn set_payload(&mut self, payload: &[u8]) -> Result<(), crate::error::NumericError> {
+        let target = self
+            .staging
+            .get_mut(..payload.len())
+            .ok_or(crate::error::ENOSPC)?;
+        target.copy_from_slice(payload);
+        self.payload_len = payload.len();
+        Ok(())
+    }
+
+    /// Number of bytes that may at most be set as payload
+    ///
+    /// The limit is an upper bound; depending on the options of the response, a payload close to
+    /// the limit may still result in an error response.
+    pub fn available_space(&self) -> usize {
+        self.staging.len()
+    }
+
+    /// Set the payload to the block of a larger resource that the request asks for, and set the
+    /// Block2 option accordingly
+    ///
+    /// This is to be called with the full representation on every request to the resource, and
+    /// produces the block chosen by the client. The block size is reduced if needed, so that the
+    /// block fits into the available space along with the response's options. This fails with
+    /// `EINVAL` if the requested block is beyond the end of the data, or if the client requested a
+    /// BERT block size.
+    #[doc(alias = "coap_blockwise_put_bytes")]
+    pub fn write_blockwise(
+        &mut self,
+        request: &Request<'_, '_>,
+        full_payload: &[u8],
+    ) -> Result<(), crate::error::NumericError> {
+        let (mut num, mut szx) = match request.block2() {
+            Some(value) => (value >> 4, value & 0x7),
+            None => (0, 6),
+        };
+        if szx == 7 {
+            return Err(crate::error::EINVAL);
+        }
+        // The block needs to fit behind the response's options and the payload marker: Observe
+        // (which gcoap may add), Content-Format and Block2 take up to 4, 3 and 4 bytes.
+        let room = self.staging.len().saturating_sub(1 + 4 + 3 + 4);
+        // Scaling down the block size (and scaling up the number to point at the same data)
+        while (16 << szx) > room {
+            if szx == 0 {
+                return Err(crate::error::ENOSPC);
+            }
+            szx -= 1;
+            num *= 2;
+        }
+        let size = 16usize << szx;
+
+        let start = num as usize * size;
+        if start > full_payload.len() || (start == full_payload.len() && num != 0) {
+            return Err(crate::error::EINVAL);
+        }
+
+        let mut slicer = MaybeUninit::uninit();
+        // unsafe: C API initializing the slicer, and then writing at most a block size into the
+        // staging area (which was checked to be large enough)
+        let written = unsafe {
+            riot_sys::coap_block_slicer_init(slicer.as_mut_ptr(), num as _, size as _);
+            riot_sys::coap_blockwise_put_bytes(
+                slicer.as_mut_ptr(),
+                self.staging.as_mut_ptr(),
+                full_payload.as_ptr() as _,
+                full_payload.len() as _,
+            )
+        } as usize;
+
+        let more = full_payload.len() > start + size;
+        self.payload_len = written;
+        self.block2 = Some(num << 4 | (more as u32) << 3 | szx);
+        Ok(())
+    }
+}
+
+/// A gcoap listener that serves a fixed set of [Resource]s
+///
+/// Like any listener, this is activated through a [RegistrationScope] or [register].
+///
+/// The resources need to be given in order of their paths, as gcoap uses that to look them up.
+///
+/// ```no_run
+/// # use riot_wrappers::gcoap::{self, Server, Request, Response};
+/// # use riot_wrappers::error::NumericError;
+/// fn hello(_: &Request, response: &mut Response) -> Result<u8, NumericError> {
+///     response.set_content_format(gcoap::ContentFormat::TextPlain);
+///     response.set_payload(b"Hello")?;
+///     Ok(0x45) // 2.05 Content
+/// }
+///
+/// let mut hello = hello;
+/// let mut server = Server::new([(c"/hello", riot_sys::COAP_GET, &mut hello)]);
+/// gcoap::scope(|greg| {
+///     greg.register(&mut server);
+///     // ...
+/// #   loop {}
+/// })
+/// ```
+pub struct Server<'a, const N: usize> {
+    handlers: [&'a mut dyn Resource; N],
+    resources: [coap_resource_t; N],
+    listener: gcoap_listener_t,
+}
+
+impl<'a, const N: usize> Server<'a, N> {
+    /// Create a server from resources, each given with its path and the allowed methods (eg.
+    /// `riot_sys::COAP_GET`)
+    pub fn new(resources: [(&'a core::ffi::CStr, u32, &'a mut dyn Resource); N]) -> Self {
+        let mut paths = [c"".as_ptr(); N];
+        let mut methods = [0; N];
+        for (i, (path, method, _)) in resources.iter().enumerate() {
+            paths[i] = path.as_ptr();
+            methods[i] = (*method).try_into().unwrap();
+        }
+        let handlers = resources.map(|(_, _, handler)| handler);
+
+        let resources = core::array::from_fn(|i| coap_resource_t {
+            path: paths[i] as _,
+            handler: Some(Self::call_resource),
+            methods: methods[i],
+            // Set when the listener is provided, as we may still move until then
+            context: 0 as *mut _,
+        });
+
+        Server {
+            handlers,
+            resources,
+            listener: gcoap_listener_t {
+                resources: 0 as *const _,
+                resources_len: 0,
+                next: 0 as *mut _,
+                ..Default::default()
+            },
+        }
+    }
+
+    unsafe extern "C" fn call_resource(
+        pkt: *mut coap_pkt_t,
+        buf: *mut u8,
+        len: riot_sys::size_t,
+        context: *mut riot_sys::coap_request_ctx_t,
+    ) -> riot_sys::ssize_t {
+        // unsafe: The context was set up in get_listener to point to the handler, which is
+        // borrowed exclusively for as long as the listener is registered
+        let handler = riot_sys::coap_request_ctx_get_context(context) as *mut &mut dyn Resource;
+        serve_raw(pkt, buf, len, &mut **handler)
+    }
+}
+
+impl<'a, const N: usize> ListenerProvider for Server<'a, N> {
+    unsafe fn get_listener(&mut self) -> &mut gcoap_listener_t {
+        for (resource, handler) in self.resources.iter_mut().zip(self.handlers.iter_mut()) {
+            resource.context = handler as *mut &mut dyn Resource as *mut libc::c_void;
+        }
+        self.listener.resources = self.resources.as_ptr();
+        self.listener.resources_len = N.try_into().unwrap();
+        self.listener.next = 0 as *mut _;
+
+        &mut self.listener
+    }
+}
+
+/// A [Resource] that is a plain function, along with its path and methods, as created by
+/// [coap_resource!](crate::coap_resource!)
+///
+/// Unlike the resources of a [Server], these carry no state of their own. Their paths are static,
+/// and they can be served through a [StaticServer].
+#[repr(transparent)]
+pub struct StaticResource(coap_resource_t);
+
+// unsafe: The path is static, and the handler does not use a context
+unsafe impl Sync for StaticResource {}
+unsafe impl Send for StaticResource {}
+
+impl StaticResource {
+    /// Create a resource with a handler that serves the request without a context
+    ///
+    /// This is used by [coap_resource!](crate::coap_resource!), which creates such a handler.
+    ///
+    /// # Safety
+    ///
+    /// The handler must be safe to call by gcoap with any request to the path (eg. by only passing
+    /// its arguments to [serve_raw]).
+    #[doc(hidden)]
+    pub const unsafe fn new(
+        path: &'static core::ffi::CStr,
+        methods: u32,
+        handler: unsafe extern "C" fn(
+            *mut coap_pkt_t,
+            *mut u8,
+            riot_sys::size_t,
+            *mut riot_sys::coap_request_ctx_t,
+        ) -> riot_sys::ssize_t,
+    ) -> Self {
+        StaticResource(coap_resource_t {
+            path: path.as_ptr() as _,
+            handler: Some(handler),
+            methods: methods as _,
+            context: 0 as *mut _,
+        })
+    }
+}
+
+/// Create a [StaticResource] from the allowed methods (eg. `riot_sys::COAP_GET`), the path and a
+/// function that serves as its [Resource]
+///
+/// This creates the C handler that gcoap calls, which presents the request to the function and
+/// builds the response from what the function sets.
+///
+/// ```no_run
+/// # use riot_wrappers::gcoap::{self, ContentFormat, Request, Response, StaticServer};
+/// # use riot_wrappers::error::NumericError;
+/// fn hello(_: &Request, response: &mut Response) -> Result<u8, NumericError> {
+///     response.set_content_format(ContentFormat::TextPlain);
+///     response.set_payload(b"Hello")?;
+///     Ok(0x45) // 2.05 Content
+/// }
+///
+/// let mut server = StaticServer::new([
+///     riot_wrappers::coap_resource!(riot_sys::COAP_GET, c"/hello", hello),
+/// ]);
+/// gcoap::scope(|greg| {
+///     greg.register(&mut server);
+///     // ...
+/// #   loop {}
+/// })
+/// ```
+#[macro_export]
+macro_rules! coap_resource {
+    ( $methods:expr, $path:expr, $fun:path ) => {{
+        unsafe extern "C" fn handler(
+            pkt: *mut $crate::riot_sys::coap_pkt_t,
+            buf: *mut u8,
+            len: $crate::riot_sys::size_t,
+            _context: *mut $crate::riot_sys::coap_request_ctx_t,
+        ) -> $crate::riot_sys::ssize_t {
+            // unsafe: Called by gcoap with its request
+            unsafe { $crate::gcoap::serve_raw(pkt, buf, len, &mut $fun) }
+        }
+        // unsafe: The handler only passes on its arguments to serve_raw
+        unsafe { $crate::gcoap::StaticResource::new($path, $methods, handler) }
+    }};
+}
+
+/// A gcoap listener that serves a fixed set of [StaticResource]s
+///
+/// Like any listener, this is activated through a [RegistrationScope] or [register]. As with a
+/// [Server], the resources need to be given in order of their paths.
+pub struct StaticServer<const N: usize> {
+    resources: [StaticResource; N],
+    listener: gcoap_listener_t,
+}
+
+impl<const N: usize> StaticServer<N> {
+    pub fn new(resources: [StaticResource; N]) -> Self {
+        StaticServer {
+            resources,
+            listener: gcoap_listener_t {
+                resources: 0 as *const _,
+                resources_len: 0,
+                next: 0 as *mut _,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl<const N: usize> ListenerProvider for StaticServer<N> {
+    unsafe fn get_listener(&mut self) -> &mut gcoap_listener_t {
+        // cast: StaticResource is transparent
+        self.listener.resources = self.resources.as_ptr() as *const coap_resource_t;
+        self.listener.resources_len = N.try_into().unwrap();
+        self.listener.next = 0 as *mut _;
+
+        &mut self.listener
+    }
+}
+
+/// Serve a request that gcoap passed to a resource handler through the given resource
+///
+/// # Safety
+///
+/// The arguments need to be those gcoap passed to the handler.
+#[doc(hidden)]
+pub unsafe fn serve_raw(
+    pkt: *mut coap_pkt_t,
+    buf: *mut u8,
+    len: riot_sys::size_t,
+    resource: &mut dyn Resource,
+) -> riot_sys::ssize_t {
+    let pb = PacketBuffer {
+        // unsafe: Valid as per the gcoap's handler API
+        pkt: unsafe { &mut *pkt },
+        buf,
+        len: len.try_into().unwrap(),
+    };
+    serve(resource, pb).try_into().unwrap()
+}
+
+/// Run the resource on a request in the buffer, and build the response in its place.
+fn serve(handler: &mut dyn Resource, mut pb: PacketBuffer) -> isize {
+    const INTERNAL_SERVER_ERROR: u8 = 5 << 5;
+
+    // The payload is staged right behind the request. As the response's options (including any
+    // gcoap adds by itself, like Observe) may take more space than those of the request, it is
+    // moved to the end of the buffer before the response is written.
+    let staging_start = pb.get_length(pb.payload().len()).min(pb.len);
+
+    // unsafe: The staging area is disjoint from the request that is viewed through Request, and
+    // the PacketBuffer is not used to write until the Response is gone.
+    let staging = unsafe {
+        core::slice::from_raw_parts_mut(pb.buf.add(staging_start), pb.len - staging_start)
+    };
+    let mut response = Response {
+        code: None,
+        content_format: None,
+        block2: None,
+        staging,
+        payload_len: 0,
+    };
+
+    let result = handler.handle(&Request { buffer: &pb }, &mut response);
+    let Response {
+        code,
+        content_format,
+        block2,
+        payload_len,
+        ..
+    } = response;
+
+    let parked_start = pb.len - payload_len;
+    // unsafe: Moving within the buffer; both areas are in bounds (the payload fit into the
+    // staging area, which extends to the end of the buffer), and ptr::copy allows overlap. The
+    // request is not accessed any more.
+    unsafe {
+        core::ptr::copy(
+            pb.buf.add(staging_start),
+            pb.buf.add(parked_start),
+            payload_len,
+        )
+    };
+
+    let built = result.and_then(|returned| {
+        pb.resp_init(code.unwrap_or(returned))?;
+        if let Some(format) = content_format {
+            pb.opt_add_uint(riot_sys::COAP_OPT_CONTENT_FORMAT as _, format.into())?;
+        }
+        if let Some(block2) = block2 {
+            pb.opt_add_uint(riot_sys::COAP_OPT_BLOCK2 as _, block2)?;
+        }
+        if payload_len > 0 {
+            let payload = pb.payload_mut();
+            if payload.len() < payload_len + 1 {
+                return Err(crate::error::ENOSPC);
+            }
+            let target = payload.as_mut_ptr();
+            // unsafe: Moving within the buffer; both areas are in bounds, and ptr::copy allows
+            // overlap. As the payload fits behind the options, the header and options written
+            // just now end before the parked payload starts, and are not affected.
+            unsafe {
+                *target = 0xff;
+                core::ptr::copy(pb.buf.add(parked_start), target.add(1), payload_len);
+            }
+            Ok(pb.get_length(payload_len + 1))
+        } else {
+            Ok(pb.get_length(0))
+        }
+    });
+
+    match built {
+        Ok(len) => len as _,
+        Err(_) => match pb.resp_init(INTERNAL_SERVER_ERROR) {
+            Ok(()) => pb.get_length(0) as _,
+            Err(e) => e.number() as _,
+        },
+    }
+}
+
+/// Response to a request sent through [Client]
+#[cfg(riot_module_sock)]
+#[derive(Debug)]
+pub struct ClientResponse {
+    /// The response code (eg. `0x45` for 2.05 Content)
+    pub code: u8,
+    /// Length of the payload copied into the response buffer
+    ///
+    /// Payload that exceeds the buffer is discarded.
+    pub payload_len: usize,
+    /// Raw value of the response's Block1 option, if present
+    pub block1: Option<u32>,
+    /// Raw value of the response's Block2 option, if present
+    pub block2: Option<u32>,
+}
+
+/// A blocking CoAP client that sends requests through gcoap
+///
+/// Retransmissions and timeouts of requests are managed by gcoap (on ztimer, as configured with
+/// `CONFIG_COAP_ACK_TIMEOUT_MS` etc. for confirmable and `CONFIG_GCOAP_NON_TIMEOUT_MSEC` for
+/// non-confirmable requests); a request that got no response in time produces an `ETIMEDOUT`
+/// error.
+#[cfg(riot_module_sock)]
+#[derive(Debug, Copy, Clone)]
+pub struct Client {
+    confirmable: bool,
+}
+
+#[cfg(riot_module_sock)]
+impl Client {
+    /// Create a client that sends confirmable requests
+    pub fn new() -> Self {
+        Client { confirmable: true }
+    }
+
+    /// Select whether requests are sent as confirmable or as non-confirmable messages
+    pub fn with_confirmable(self, confirmable: bool) -> Self {
+        Client { confirmable }
+    }
+
+    /// Send a GET request and wait for the response, whose payload is copied into
+    /// `response_payload`
+    pub fn get(
+        &self,
+        remote: &crate::socket::UdpEp,
+        path: &core::ffi::CStr,
+        response_payload: &mut [u8],
+    ) -> Result<ClientResponse, crate::error::NumericError> {
+        self.request(
+            remote,
+            riot_sys::COAP_METHOD_GET as _,
+            path,
+            &[],
+            response_payload,
+        )
+    }
+
+    /// Send a POST request with the given payload and wait for the response, whose payload is
+    /// copied into `response_payload`
+    pub fn post(
+        &self,
+        remote: &crate::socket::UdpEp,
+        path: &core::ffi::CStr,
+        payload: &[u8],
+        response_payload: &mut [u8],
+    ) -> Result<ClientResponse, crate::error::NumericError> {
+        self.request(
+            remote,
+            riot_sys::COAP_METHOD_POST as _,
+            path,
+            payload,
+            response_payload,
+        )
+    }
+
+    /// Send a request with any method code and wait for the response
+    #[doc(alias = "gcoap_req_init")]
+    #[doc(alias = "gcoap_req_send")]
+    pub fn request(
+        &self,
+        remote: &crate::socket::UdpEp,
+        code: u8,
+        path: &core::ffi::CStr,
+        payload: &[u8],
+        response_payload: &mut [u8],
+    ) -> Result<ClientResponse, crate::error::NumericError> {
+        self.request_with_blocks(remote, code, path, payload, None, None, response_payload)
+    }
+
+    /// Send a request with optional Block1 and Block2 options and wait for the response
+    #[allow(clippy::too_many_arguments)]
+    fn request_with_blocks(
+        &self,
+        remote: &crate::socket::UdpEp,
+        code: u8,
+        path: &core::ffi::CStr,
+        payload: &[u8],
+        mut block1: Option<riot_sys::coap_block1_t>,
+        mut block2: Option<riot_sys::coap_block1_t>,
+        response_payload: &mut [u8],
+    ) -> Result<ClientResponse, crate::error::NumericError> {
+        let mut buf = [0u8; riot_sys::CONFIG_GCOAP_PDU_BUF_SIZE as usize];
+        let mut pkt: MaybeUninit<coap_pkt_t> = MaybeUninit::uninit();
+
+        // unsafe: C API with a valid buffer of the indicated size
+        unsafe {
+            riot_sys::gcoap_req_init_path_buffer(
+                pkt.as_mut_ptr(),
+                buf.as_mut_ptr(),
+                buf.len() as _,
+                code.into(),
+                path.as_ptr() as _,
+                path.to_bytes().len() as _,
+            )
+        }
+        .negative_to_error()?;
+        // unsafe: Initialized by gcoap_req_init_path_buffer on success
+        let pkt = unsafe { pkt.assume_init_mut() };
+
+        // The message type is in bits 4 and 5 of the first header byte. It is set directly as
+        // the accessors for it have changed across RIOT versions.
+        let msg_type = if self.confirmable {
+            riot_sys::COAP_TYPE_CON
+        } else {
+            riot_sys::COAP_TYPE_NON
+        } as u8;
+        buf[0] = (buf[0] & !0x30) | (msg_type << 4);
+
+        // Options need to be added in order of their numbers, and Uri-Path is already in
+        if let Some(block2) = block2.as_mut() {
+            // unsafe: C API on the initialized packet
+            unsafe {
+                riot_sys::inline::coap_opt_add_block2_control(
+                    crate::inline_cast_mut(&mut *pkt as *mut _),
+                    crate::inline_cast_mut(block2 as *mut _),
+                )
+            }
+            .negative_to_error()?;
+        }
+        if let Some(block1) = block1.as_mut() {
+            // unsafe: C API on the initialized packet
+            unsafe {
+                riot_sys::inline::coap_opt_add_block1_control(
+                    crate::inline_cast_mut(&mut *pkt as *mut _),
+                    crate::inline_cast_mut(block1 as *mut _),
+                )
+            }
+            .negative_to_error()?;
+        }
+
+        let flags = if payload.is_empty() {
+            riot_sys::COAP_OPT_FINISH_NONE
+        } else {
+            riot_sys::COAP_OPT_FINISH_PAYLOAD
+        };
+        // unsafe: C API on the initialized packet
+        let header_len =
+            unsafe { riot_sys::coap_opt_finish(pkt, flags as _) }.negative_to_error()? as usize;
+        if (pkt.payload_len as usize) < payload.len() {
+            return Err(crate::error::ENOSPC);
+        }
+        // unsafe: The payload pointer is in the buffer, with enough space as checked above
+        unsafe { core::ptr::copy_nonoverlapping(payload.as_ptr(), pkt.payload, payload.len()) };
+        let len = header_len + payload.len();
+
+        let mut exchange = Exchange {
+            // unsafe: Side effect free C macro
+            done: unsafe { riot_sys::macro_MUTEX_INIT() },
+            result: Err(crate::error::EAGAIN),
+            payload: response_payload,
+        };
+        // Locked here, unlocked by the response handler
+        unsafe { riot_sys::mutex_lock(crate::inline_cast_mut(&mut exchange.done as *mut _)) };
+
+        // unsafe: The exchange stays in place until the response handler was called, for gcoap
+        // calls it exactly once (with a response, an error or a timeout) if the request was sent.
+        let sent = unsafe {
+            riot_sys::gcoap_req_send(
+                buf.as_ptr(),
+                len as _,
+                remote.as_ref(),
+                core::ptr::null(),
+                Some(Exchange::handle_response),
+                &mut exchange as *mut Exchange as *mut libc::c_void,
+                0, // GCOAP_SOCKET_TYPE_UNDEF
+            )
+        };
+        if sent <= 0 {
+            return Err(sent.negative_to_error().err().unwrap_or(
+                crate::error::NumericError::from_constant(riot_sys::EHOSTUNREACH as _),
+            ));
+        }
+
+        // Blocks until the response handler unlocks it
+        unsafe { riot_sys::mutex_lock(crate::inline_cast_mut(&mut exchange.done as *mut _)) };
+
+        exchange.result
+    }
+}
+
+/// Block size exponent (SZX) that blockwise transfers start with
+///
+/// This is the largest block size permitted by `CONFIG_NANOCOAP_BLOCK_SIZE_EXP_MAX` that still
+/// leaves half the PDU buffer for the message's header and options.
+#[cfg(riot_module_sock)]
+const INITIAL_SZX: u8 = {
+    let mut szx = riot_sys::CONFIG_NANOCOAP_BLOCK_SIZE_EXP_MAX as u8 - 4;
+    while szx > 0 && (16 << szx) > riot_sys::CONFIG_GCOAP_PDU_BUF_SIZE as usize / 2 {
+        szx -= 1;
+    }
+    szx
+};
+
+/// Control data of a block option (in either direction)
+#[cfg(riot_module_sock)]
+fn block_control(blknum: u32, szx: u8, more: bool) -> riot_sys::coap_block1_t {
+    riot_sys::coap_block1_t {
+        offset: (blknum as usize) << (szx + 4),
+        blknum: blknum as _,
+        szx,
+        more: more as _,
+    }
+}
+
+/// A single block of a resource obtained through [BlockwiseGet]
+#[cfg(riot_module_sock)]
+#[derive(Debug)]
+pub struct Block2Response<'b> {
+    /// The response code (eg. `0x45` for 2.05 Content)
+    pub code: u8,
+    /// Offset of the block's payload in the full representation
+    pub offset: usize,
+    /// The block's payload, in the buffer passed to [BlockwiseGet::next_block]
+    pub payload: &'b [u8],
+}
+
+/// A blockwise (RFC 7959) GET request, which fetches the blocks of the response one at a time
+///
+/// Every call to [next_block()](Self::next_block) sends a request for the next block. The block
+/// size starts at the largest that the configured PDU buffer accommodates, and is reduced when the
+/// server picks a smaller one, or when the caller's buffer is smaller. The transfer ends after the
+/// last block, or after the first error (which may also be a non-successful response code).
+/// Responses without a Block2 option are treated like a single last block.
+///
+/// ```no_run
+/// # use riot_wrappers::gcoap::{BlockwiseGet, Client};
+/// # let remote: riot_wrappers::socket::UdpEp = unimplemented!();
+/// let client = Client::new();
+/// let mut transfer = BlockwiseGet::new(&client, &remote, c"/large");
+/// let mut buf = [0; 256];
+/// while let Some(block) = transfer.next_block(&mut buf) {
+///     let block = block.unwrap();
+///     riot_wrappers::println!("{}: {:?}", block.offset, block.payload);
+/// }
+/// ```
+#[cfg(riot_module_sock)]
+#[doc(alias = "coap_opt_add_block2_control")]
+pub struct BlockwiseGet<'a> {
+    client: &'a Client,
+    remote: &'a crate::socket::UdpEp,
+    path: &'a core::ffi::CStr,
+    /// Number and size exponent of the next block to request, or None when done
+    next: Option<(u32, u8)>,
+}
+
+#[cfg(riot_module_sock)]
+impl<'a> BlockwiseGet<'a> {
+    pub fn new(
+        client: &'a Client,
+        remote: &'a crate::socket::UdpEp,
+        path: &'a core::ffi::CStr,
+    ) -> Self {
+        BlockwiseGet {
+            client,
+            remote,
+            path,
+            next: Some((0, INITIAL_SZX)),
+        }
+    }
+
+    /// Request the next block, and copy its payload into the buffer
+    ///
+    /// This returns None once the transfer is complete. Blocks are requested in a size that fits
+    /// the buffer (but no smaller than 16 bytes, beyond which the payload is truncated).
+    pub fn next_block<'b>(
+        &mut self,
+        buf: &'b mut [u8],
+    ) -> Option<Result<Block2Response<'b>, crate::error::NumericError>> {
+        let (mut num, mut szx) = self.next.take()?;
+        while szx > 0 && (16 << szx) > buf.len() {
+            // Same offset in blocks of half the size
+            num <<= 1;
+            szx -= 1;
+        }
+
+        let response = match self.client.request_with_blocks(
+            self.remote,
+            riot_sys::COAP_METHOD_GET as _,
+            self.path,
+            &[],
+            None,
+            Some(block_control(num, szx, false)),
+            buf,
+        ) {
+            Ok(response) => response,
+            Err(e) => return Some(Err(e)),
+        };
+        let mut offset = (num as usize) << (szx + 4);
+
+        if response.code >> 5 == 2 {
+            if let Some(value) = response.block2 {
+                let (resp_num, more, resp_szx) =
+                    (value >> 4, value & 0x8 != 0, (value & 0x7) as u8);
+                // BERT is not supported
+                if resp_szx == 7 || resp_szx > szx {
+                    return Some(Err(crate::error::NumericError::from_constant(
+                        riot_sys::EBADMSG as _,
+                    )));
+                }
+                offset = (resp_num as usize) << (resp_szx + 4);
+                if more {
+                    // The next request continues with the server's (possibly smaller) block size
+                    let next_offset = offset + (16 << resp_szx);
+                    self.next = Some(((next_offset >> (resp_szx + 4)) as u32, resp_szx));
+                }
+            }
+        }
+
+        Some(Ok(Block2Response {
+            code: response.code,
+            offset,
+            payload: &buf[..response.payload_len],
+        }))
+    }
+}
+
+/// A blockwise (RFC 7959) PUT request, which sends a large payload in Block1 fragments
+///
+/// The block size starts like in [BlockwiseGet], and is reduced when the server asks for smaller
+/// blocks.
+#[cfg(riot_module_sock)]
+#[doc(alias = "coap_opt_add_block1_control")]
+pub struct BlockwisePut<'a> {
+    client: &'a Client,
+    remote: &'a crate::socket::UdpEp,
+    path: &'a core::ffi::CStr,
+    payload: &'a [u8],
+}
+
+#[cfg(riot_module_sock)]
+impl<'a> BlockwisePut<'a> {
+    pub fn new(
+        client: &'a Client,
+        remote: &'a crate::socket::UdpEp,
+        path: &'a core::ffi::CStr,
+        payload: &'a [u8],
+    ) -> Self {
+        BlockwisePut {
+            client,
+            remote,
+            path,
+            payload,
+        }
+    }
+
+    /// Send all blocks, and return the response to the final block, whose payload is copied
+    /// into `response_payload`
+    ///
+    /// Any unsuccessful response to an intermediate block ends the transfer, and is returned.
+    pub fn send(
+        self,
+        response_payload: &mut [u8],
+    ) -> Result<ClientResponse, crate::error::NumericError> {
+        let mut offset = 0;
+        let mut szx = INITIAL_SZX;
+        loop {
+            let size = 16usize << szx;
+            let end = (offset + size).min(self.payload.len());
+            let more = end < self.payload.len();
+
+            let response = self.client.request_with_blocks(
+                self.remote,
+                riot_sys::COAP_METHOD_PUT as _,
+                self.path,
+                &self.payload[offset..end],
+                Some(block_control((offset / size) as u32, szx, more)),
+                None,
+                response_payload,
+            )?;
+
+            if !more || response.code >> 5 != 2 {
+                return Ok(response);
+            }
+
+            offset = end;
+            // The server may ask for smaller blocks in its response's Block1 option
+            if let Some(value) = response.block1 {
+                let resp_szx = (value & 0x7) as u8;
+                if resp_szx < szx {
+                    szx = resp_szx;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(riot_module_sock)]
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// State shared between a [Client] request and its response handler
+#[cfg(riot_module_sock)]
+struct Exchange<'a> {
+    done: riot_sys::inline::mutex_t,
+    result: Result<ClientResponse, crate::error::NumericError>,
+    payload: &'a mut [u8],
+}
+
+#[cfg(riot_module_sock)]
+impl Exchange<'_> {
+    unsafe extern "C" fn handle_response(
+        memo: *const riot_sys::gcoap_request_memo_t,
+        pdu: *mut coap_pkt_t,
+        _remote: *const riot_sys::sock_udp_ep_t,
+    ) {
+        // unsafe: The context was set to the exchange, which waits for this to be called
+        let exchange = &mut *((*memo).context as *mut Exchange);
+
+        exchange.result = match (*memo).state as _ {
+            riot_sys::GCOAP_MEMO_RESP | riot_sys::GCOAP_MEMO_RESP_TRUNC => {
+                let pb = PacketBuffer {
+                    pkt: &mut *pdu,
+                    buf: core::ptr::null_mut(),
+                    len: 0,
+                };
+                let payload = pb.payload();
+                let payload_len = payload.len().min(exchange.payload.len());
+                exchange.payload[..payload_len].copy_from_slice(&payload[..payload_len]);
+                Ok(ClientResponse {
+                    code: pb.get_code_raw(),
+                    payload_len,
+                    block1: pb.opt_get_uint(riot_sys::COAP_OPT_BLOCK1 as _),
+                    block2: pb.opt_get_uint(riot_sys::COAP_OPT_BLOCK2 as _),
+                })
+            }
+            riot_sys::GCOAP_MEMO_TIMEOUT => Err(crate::error::NumericError::from_constant(
+                riot_sys::ETIMEDOUT as _,
+            )),
+            _ => Err(crate::error::NumericError::from_constant(
+                riot_sys::EBADMSG as _,
+            )),
+        };
+
+        riot_sys::mutex_unlock(crate::inline_cast_mut(&mut exchange.done as *mut _));
+    }
+}
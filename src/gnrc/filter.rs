@@ -0,0 +1,113 @@
+//! Filtering the packets a thread receives from GNRC
+//!
+//! GNRC delivers packets to all registrations of a net type and demux context independently;
+//! a registration can not keep packets from other registrations. Filtering is thus done by
+//! interposing: Instead of the target thread, a [Filter] is registered (as a netreg callback), and
+//! it relays the packets it accepts to the target thread, which itself does not register for the
+//! context.
+//!
+//! ```no_run
+//! # use core::pin::pin;
+//! # use core::sync::atomic::{AtomicUsize, Ordering};
+//! # use riot_wrappers::gnrc::filter::{Decision, Filter};
+//! # use riot_wrappers::gnrc::netreg::FullDemuxContext;
+//! # use riot_wrappers::gnrc_pktbuf::{Pktsnip, Shared};
+//! # let context: FullDemuxContext = unimplemented!();
+//! // Let through only one in four packets to the current thread
+//! static COUNT: AtomicUsize = AtomicUsize::new(0);
+//! let quarter = |_: &Pktsnip<Shared>| match COUNT.fetch_add(1, Ordering::Relaxed) % 4 {
+//!     0 => Decision::Accept,
+//!     _ => Decision::Drop,
+//! };
+//! let slot = pin!(Default::default());
+//! let handle = Filter::new(riot_wrappers::thread::get_pid(), quarter)
+//!     .register(slot, context)
+//!     .unwrap();
+//!
+//! // ... receive and process the packets ...
+//!
+//! // Stop filtering
+//! drop(handle);
+//! ```
+//!
+//! Filters that stay for the whole runtime can also be registered through
+//! [Filter::register_static].
+
+use core::pin::Pin;
+
+use super::netreg::callback::{
+    register, register_static, Callback, CallbackSlot, Command, Registered, Slot,
+};
+use super::netreg::FullDemuxContext;
+use crate::error::NumericError;
+use crate::gnrc_pktbuf::{Pktsnip, Shared};
+use crate::thread::KernelPID;
+
+/// What a [Filter] does with a packet
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Decision {
+    /// Pass the packet on to the target thread
+    Accept,
+    /// Release the packet
+    Drop,
+}
+
+/// A netreg [Callback] that relays the packets accepted by a filter function to a target thread
+///
+/// Received packets are relayed as received packets, packets being sent as packets to send
+/// (eg. when filtering the packets towards a network interface). Packets that the target thread
+/// can not take (because its message queue is full) are dropped.
+///
+/// The filter runs in the thread that dispatches the packet, so it should be quick.
+pub struct Filter<F> {
+    target: KernelPID,
+    filter: F,
+}
+
+impl<F> Filter<F>
+where
+    F: Fn(&Pktsnip<Shared>) -> Decision + Send,
+{
+    pub fn new(target: KernelPID, filter: F) -> Self {
+        Filter { target, filter }
+    }
+
+    /// Register the filter for the given context, for as long as the returned handle is kept
+    #[doc(alias = "gnrc_netreg_register")]
+    pub fn register<'a>(
+        self,
+        slot: Pin<&'a mut CallbackSlot<Self>>,
+        context: FullDemuxContext,
+    ) -> Result<FilterHandle<'a, F>, NumericError> {
+        register(slot, self, context)
+    }
+
+    /// Register the filter for the given context
+    ///
+    /// Like [register_static], this is permanent.
+    pub fn register_static(self, slot: &'static mut Slot<Self>, context: FullDemuxContext) {
+        register_static(slot, self, context)
+    }
+}
+
+/// A registered [Filter]
+///
+/// Dropping the handle unregisters the filter, after which the target thread receives no more
+/// packets for the context.
+pub type FilterHandle<'a, F> = Registered<'a, Filter<F>>;
+
+impl<F> Callback for Filter<F>
+where
+    F: Fn(&Pktsnip<Shared>) -> Decision + Send,
+{
+    fn called(&self, cmd: Command, snip: Pktsnip<Shared>) {
+        if (self.filter)(&snip) == Decision::Drop {
+            return;
+        }
+        // Errors are not reported further, as there would be no one to report them to
+        let _ = match cmd {
+            Command::Receive => super::netapi::receive(self.target, snip),
+            Command::Send => super::netapi::send(self.target, snip),
+        };
+    }
+}
@@ -1,5 +1,7 @@
 //! Components for interacting with ICMPv6 messages on GNRC
 
+pub mod error;
+
 /// Type of an ICMPv6 Echo packet
 ///
 /// Used both to build echo packets (which, admittedly, are mainly requests in
@@ -45,3 +47,270 @@ impl<'a> Pktsnip<Writable> {
         }
     }
 }
+
+#[cfg(all(riot_module_gnrc_icmpv6_echo, riot_module_ztimer, riot_module_core_msg))]
+pub use echo::*;
+
+#[cfg(all(riot_module_gnrc_icmpv6_echo, riot_module_ztimer, riot_module_core_msg))]
+mod echo {
+    use core::pin::Pin;
+
+    use riot_sys::gnrc_nettype_t_GNRC_NETTYPE_ICMPV6 as GNRC_NETTYPE_ICMPV6;
+    use riot_sys::gnrc_nettype_t_GNRC_NETTYPE_IPV6 as GNRC_NETTYPE_IPV6;
+
+    use super::EchoType;
+    use crate::error::NumericError;
+    use crate::gnrc::ipv6::Address;
+    use crate::gnrc::netreg::FullDemuxContext;
+    use crate::gnrc::Netif;
+    use crate::gnrc_pktbuf::{NotEnoughSpace, Pktsnip, Shared, Writable};
+    use crate::msg::{Msg, OpaqueMsg, WrapsMsgT};
+    use crate::thread::KernelPID;
+    use crate::ztimer::{Clock, LockedClock, Ticks, Timestamp};
+
+    /// Error type of [ping]
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub enum PingError {
+        /// The packet buffer had no space for the request
+        NotEnoughSpace,
+        /// No GNRC component was registered to take the packet
+        NotDispatched,
+        /// No matching reply arrived in time (only produced by [PingReceiver::ping_and_wait])
+        Timeout,
+    }
+
+    impl From<NotEnoughSpace> for PingError {
+        fn from(_: NotEnoughSpace) -> Self {
+            PingError::NotEnoughSpace
+        }
+    }
+
+    /// Send an ICMPv6 echo request to the destination.
+    ///
+    /// If no interface is given, GNRC picks one (which for link-local destination addresses only
+    /// works if there is a single interface).
+    ///
+    /// Replies can be received by a [PingReceiver].
+    #[doc(alias = "gnrc_icmpv6_echo_send")]
+    pub fn ping(
+        dst: &Address,
+        iface: Option<&Netif>,
+        id: u16,
+        seq: u16,
+        payload: &[u8],
+    ) -> Result<(), PingError> {
+        let pkt = Pktsnip::<Writable>::icmpv6_echo_build(EchoType::Request, id, seq, payload)?
+            .ipv6_hdr_build(None, Some(dst))?;
+        let pkt = match iface {
+            Some(iface) => pkt
+                .netif_hdr_builder()
+                .without_link_layer_addresses()
+                .with_if_pid(iface.pid())
+                .finish()?,
+            None => pkt,
+        };
+
+        match crate::gnrc::netapi::dispatch_send(
+            GNRC_NETTYPE_IPV6,
+            riot_sys::GNRC_NETREG_DEMUX_CTX_ALL,
+            pkt,
+        ) {
+            0 => Err(PingError::NotDispatched),
+            _ => Ok(()),
+        }
+    }
+
+    /// An ICMPv6 echo reply received through a [PingReceiver]
+    #[derive(Debug)]
+    pub struct EchoReply {
+        pub src: Address,
+        pub id: u16,
+        pub seq: u16,
+        /// Time since the matching request was sent through the receiver, if it was
+        pub rtt: Option<Ticks<1000>>,
+    }
+
+    /// A place where a [PingReceiver]'s netreg entry can be stored
+    pub type PingReceiverSlot = crate::gnrc::netreg::EntrySlot;
+
+    /// Number of requests whose send time a [PingReceiver] remembers
+    const PENDING: usize = 4;
+
+    /// A registration for ICMPv6 echo replies on the current thread
+    ///
+    /// Requests sent through [PingReceiver::ping] use the receiver's identifier, and their send
+    /// time is kept to report the round trip time for the most recent few of them. Replies are
+    /// delivered as messages; for them not to get lost while the thread is busy, it should have a
+    /// message queue set up.
+    ///
+    /// ## Invariants
+    ///
+    /// While this is active, the slot holds its registration.
+    pub struct PingReceiver<'a> {
+        slot: Pin<&'a mut PingReceiverSlot>,
+        pid: KernelPID,
+        id: u16,
+        clock: Clock<1000>,
+        locked: LockedClock<1000>,
+        sent: [Option<(u16, Timestamp<1000>)>; PENDING],
+        next_sent: usize,
+    }
+
+    impl<'a> PingReceiver<'a> {
+        /// Register for ICMPv6 echo replies for the current thread.
+        ///
+        /// Only replies with the given identifier are reported. The millisecond clock (eg.
+        /// [Clock::msec_unbound()]) is kept acquired while the receiver exists, and is used to
+        /// measure round trip times.
+        #[doc(alias = "gnrc_netreg_register")]
+        pub fn new(
+            id: u16,
+            clock: Clock<1000>,
+            mut slot: Pin<&'a mut PingReceiverSlot>,
+        ) -> Result<Self, NumericError> {
+            let pid = crate::thread::get_pid();
+
+            slot.as_mut().register_pid(
+                FullDemuxContext::new_raw(GNRC_NETTYPE_ICMPV6, EchoType::Reply.into()),
+                pid,
+            )?;
+
+            Ok(PingReceiver {
+                slot,
+                pid,
+                id,
+                clock,
+                locked: clock.acquire(),
+                sent: [None; PENDING],
+                next_sent: 0,
+            })
+        }
+
+        /// Send an echo request with the receiver's identifier (see [ping]), and remember its
+        /// send time.
+        pub fn ping(
+            &mut self,
+            dst: &Address,
+            iface: Option<&Netif>,
+            seq: u16,
+            payload: &[u8],
+        ) -> Result<(), PingError> {
+            let now = self.locked.now();
+            ping(dst, iface, self.id, seq, payload)?;
+            self.sent[self.next_sent] = Some((seq, now));
+            self.next_sent = (self.next_sent + 1) % PENDING;
+            Ok(())
+        }
+
+        /// Block until an echo reply with the receiver's identifier arrives.
+        ///
+        /// Other messages the thread receives are discarded, so this is best used in threads
+        /// dedicated to pinging.
+        ///
+        /// ## Panics
+        ///
+        /// This panics if called from another thread than the one that created the receiver.
+        pub fn receive(&mut self) -> EchoReply {
+            self.assert_thread();
+            loop {
+                if let Some(reply) = self.process(OpaqueMsg::receive()) {
+                    return reply;
+                }
+            }
+        }
+
+        /// Like [Self::receive], but give up if no echo reply with the receiver's identifier
+        /// arrives within the timeout
+        ///
+        /// ## Panics
+        ///
+        /// This panics if called from another thread than the one that created the receiver.
+        #[doc(alias = "ztimer_msg_receive_timeout")]
+        pub fn receive_timeout(&mut self, timeout: Ticks<1000>) -> Option<EchoReply> {
+            self.receive_matching(timeout, |_| true)
+        }
+
+        /// Send an echo request (see [Self::ping]), and wait for its reply
+        ///
+        /// Replies to other requests that arrive in the meantime are discarded. Unlike with
+        /// [Self::receive], the round trip time is always present in the result.
+        pub fn ping_and_wait(
+            &mut self,
+            dst: &Address,
+            iface: Option<&Netif>,
+            seq: u16,
+            payload: &[u8],
+            timeout: Ticks<1000>,
+        ) -> Result<EchoReply, PingError> {
+            self.ping(dst, iface, seq, payload)?;
+            self.receive_matching(timeout, |reply| reply.seq == seq && reply.rtt.is_some())
+                .ok_or(PingError::Timeout)
+        }
+
+        fn receive_matching(
+            &mut self,
+            timeout: Ticks<1000>,
+            mut filter: impl FnMut(&EchoReply) -> bool,
+        ) -> Option<EchoReply> {
+            self.assert_thread();
+            let clock = crate::thread::InThread::new()
+                .expect("Ping receiver used in an interrupt")
+                .promote(self.clock);
+            let start = self.locked.now();
+            loop {
+                let remaining = timeout.0.checked_sub((self.locked.now() - start).0)?;
+                let msg = OpaqueMsg::receive_timeout(&clock, Ticks(remaining))?;
+                match self.process(msg) {
+                    Some(reply) if filter(&reply) => return Some(reply),
+                    _ => continue,
+                }
+            }
+        }
+
+        fn assert_thread(&self) {
+            assert!(
+                crate::thread::get_pid() == self.pid,
+                "Ping receiver used outside the thread it was created in"
+            );
+        }
+
+        /// Extract an echo reply with the receiver's identifier from a received message
+        fn process(&mut self, msg: OpaqueMsg) -> Option<EchoReply> {
+            if msg.get_type() != riot_sys::GNRC_NETAPI_MSG_TYPE_RCV as u16 {
+                return None;
+            }
+            let now = self.locked.now();
+            // unsafe: Messages of that type carry a packet along with its ownership
+            let pkt = unsafe { Pktsnip::<Shared>::from_ptr(msg.view().content.ptr as *mut _) };
+
+            let src = *pkt.ipv6_get_header()?.src();
+            let icmpv6 = pkt.search_type(GNRC_NETTYPE_ICMPV6)?;
+            // Type, code, checksum, identifier, sequence number
+            let &[_, _, _, _, id0, id1, seq0, seq1, ..] = icmpv6.data else {
+                return None;
+            };
+            let id = u16::from_be_bytes([id0, id1]);
+            let seq = u16::from_be_bytes([seq0, seq1]);
+            if id != self.id {
+                return None;
+            }
+
+            let rtt = self
+                .sent
+                .iter_mut()
+                .find(|entry| matches!(entry, Some((sent_seq, _)) if *sent_seq == seq))
+                .and_then(|s| s.take())
+                .map(|(_, sent)| now - sent);
+
+            Some(EchoReply { src, id, seq, rtt })
+        }
+    }
+
+    impl Drop for PingReceiver<'_> {
+        fn drop(&mut self) {
+            // Ends the registration already now rather than when the slot goes
+            self.slot.as_mut().close();
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Decoding of ICMPv6 error messages
+//!
+//! Error messages (as opposed to informational messages like echo requests) report why a packet
+//! sent earlier could not be delivered. They carry as much of the invoking packet as fits, which
+//! allows matching them to the sent packet.
+//!
+//! To receive them, register for the ICMPv6 nettype with the error's type as demux context, and
+//! parse the received packets with [Icmpv6Error::parse].
+
+use riot_sys::gnrc_nettype_t_GNRC_NETTYPE_ICMPV6 as GNRC_NETTYPE_ICMPV6;
+
+use crate::gnrc_pktbuf::{Mode, Pktsnip};
+
+/// Length of the ICMPv6 header preceding the invoking packet in all error messages
+const HEADER_LEN: usize = 8;
+
+/// Reasons for which [Icmpv6Error::parse] can fail
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The packet does not contain an ICMPv6 snip
+    NotIcmpv6,
+    /// The ICMPv6 message is not an error message (or an error message of an unknown type)
+    NotAnError,
+    /// The ICMPv6 message is too short for an error message
+    Truncated,
+}
+
+/// Code of a Destination Unreachable message (RFC 4443 Section 3.1)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DestUnreachableCode {
+    NoRoute,
+    AdministrativelyProhibited,
+    BeyondScope,
+    AddressUnreachable,
+    PortUnreachable,
+    SourcePolicyFailed,
+    RejectRoute,
+    /// Any code not known to this implementation
+    Other(u8),
+}
+
+impl From<u8> for DestUnreachableCode {
+    fn from(code: u8) -> Self {
+        use DestUnreachableCode::*;
+        match code {
+            0 => NoRoute,
+            1 => AdministrativelyProhibited,
+            2 => BeyondScope,
+            3 => AddressUnreachable,
+            4 => PortUnreachable,
+            5 => SourcePolicyFailed,
+            6 => RejectRoute,
+            c => Other(c),
+        }
+    }
+}
+
+/// Code of a Time Exceeded message (RFC 4443 Section 3.3)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeExceededCode {
+    /// The hop limit was exceeded in transit (this is what traceroute uses)
+    HopLimit,
+    /// Reassembly of a fragmented packet timed out
+    FragmentReassembly,
+    /// Any code not known to this implementation
+    Other(u8),
+}
+
+impl From<u8> for TimeExceededCode {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => TimeExceededCode::HopLimit,
+            1 => TimeExceededCode::FragmentReassembly,
+            c => TimeExceededCode::Other(c),
+        }
+    }
+}
+
+/// A decoded ICMPv6 error message
+///
+/// The `invoking_packet` is the part of the packet that caused the error, starting at its IPv6
+/// header.
+#[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum Icmpv6Error<'a> {
+    DestUnreachable {
+        code: DestUnreachableCode,
+        invoking_packet: &'a [u8],
+    },
+    PacketTooBig {
+        /// The MTU of the link the packet could not be forwarded on
+        mtu: u32,
+        invoking_packet: &'a [u8],
+    },
+    TimeExceeded {
+        code: TimeExceededCode,
+        invoking_packet: &'a [u8],
+    },
+    ParameterProblem {
+        code: u8,
+        /// Offset into the invoking packet at which the problem was found
+        pointer: u32,
+        invoking_packet: &'a [u8],
+    },
+}
+
+impl<'a> Icmpv6Error<'a> {
+    /// Decode the ICMPv6 snip of a received packet as an error message
+    pub fn parse<M: Mode>(pkt: &'a Pktsnip<M>) -> Result<Self, ParseError> {
+        let data = pkt
+            .search_type(GNRC_NETTYPE_ICMPV6)
+            .ok_or(ParseError::NotIcmpv6)?
+            .data;
+        if data.len() < HEADER_LEN {
+            return Err(ParseError::Truncated);
+        }
+        let code = data[1];
+        let field = u32::from_be_bytes(data[4..8].try_into().unwrap());
+        let invoking_packet = &data[HEADER_LEN..];
+
+        Ok(match data[0] as u32 {
+            riot_sys::ICMPV6_DST_UNR => Icmpv6Error::DestUnreachable {
+                code: code.into(),
+                invoking_packet,
+            },
+            riot_sys::ICMPV6_PKT_TOO_BIG => Icmpv6Error::PacketTooBig {
+                mtu: field,
+                invoking_packet,
+            },
+            riot_sys::ICMPV6_TIME_EXC => Icmpv6Error::TimeExceeded {
+                code: code.into(),
+                invoking_packet,
+            },
+            riot_sys::ICMPV6_PARAM_PROB => Icmpv6Error::ParameterProblem {
+                code,
+                pointer: field,
+                invoking_packet,
+            },
+            _ => return Err(ParseError::NotAnError),
+        })
+    }
+
+    /// The part of the invoking packet that was returned in the error message
+    pub fn invoking_packet(&self) -> &'a [u8] {
+        match self {
+            Icmpv6Error::DestUnreachable {
+                invoking_packet, ..
+            }
+            | Icmpv6Error::PacketTooBig {
+                invoking_packet, ..
+            }
+            | Icmpv6Error::TimeExceeded {
+                invoking_packet, ..
+            }
+            | Icmpv6Error::ParameterProblem {
+                invoking_packet, ..
+            } => invoking_packet,
+        }
+    }
+
+    /// The destination address of the invoking packet
+    ///
+    /// This is the information needed to, for example, update a path MTU.
+    #[cfg(riot_module_ipv6)]
+    pub fn invoking_destination(&self) -> Option<crate::gnrc::ipv6::Address> {
+        // Length of the fixed IPv6 header at the start of the invoking packet
+        const IPV6_HEADER_LEN: usize = 40;
+        let header = self.invoking_packet().get(..IPV6_HEADER_LEN)?;
+        let dst: [u8; 16] = header[24..40].try_into().unwrap();
+        Some((&core::net::Ipv6Addr::from(dst)).into())
+    }
+}
@@ -1,5 +1,8 @@
 //! Components for interacting with IPv6 messages on GNRC
 
+#[cfg(riot_module_gnrc_ipv6_ext)]
+pub mod ext_hdr;
+
 use core::mem::MaybeUninit;
 
 use riot_sys::{ipv6_addr_t, kernel_pid_t};
@@ -26,6 +29,78 @@ impl super::Netif {
         addrs.len = (result.negative_to_error()? as usize) / core::mem::size_of::<Address>();
         Ok(addrs)
     }
+
+    /// Assign an IPv6 address to the interface
+    ///
+    /// The address is added in the valid state, with the given prefix length (which needs to be in
+    /// the range 1 to 128, or it fails with `EINVAL`). It fails if the interface's address table is
+    /// full, or if the address is not acceptable to the interface.
+    #[doc(alias = "gnrc_netif_ipv6_addr_add")]
+    pub fn set_ipv6_addr(&self, addr: &Address, prefix_len: u8) -> Result<(), NumericError> {
+        if prefix_len == 0 || prefix_len > 128 {
+            return Err(crate::error::EINVAL);
+        }
+        // unsafe: C API with valid pointers (the netif is valid by construction of Netif)
+        unsafe {
+            riot_sys::inline::gnrc_netif_ipv6_addr_add(
+                crate::inline_cast(self.0),
+                crate::inline_cast(addr.as_ptr()),
+                prefix_len.into(),
+                riot_sys::GNRC_NETIF_IPV6_ADDRS_FLAGS_STATE_VALID as _,
+            )
+        }
+        .negative_to_error()
+        .map(|_| ())
+    }
+
+    /// Remove an IPv6 address from the interface
+    #[doc(alias = "gnrc_netif_ipv6_addr_remove")]
+    pub fn remove_ipv6_addr(&self, addr: &Address) -> Result<(), NumericError> {
+        // unsafe: C API with valid pointers (the netif is valid by construction of Netif)
+        unsafe {
+            riot_sys::inline::gnrc_netif_ipv6_addr_remove(
+                crate::inline_cast(self.0),
+                crate::inline_cast(addr.as_ptr()),
+            )
+        }
+        .negative_to_error()
+        .map(|_| ())
+    }
+
+    /// Join an IPv6 multicast group on the interface
+    ///
+    /// Packets sent to the group are then accepted on the interface. Joins are not counted: After
+    /// a [Self::leave_ipv6_group], the interface is not in the group any more, no matter how often
+    /// it was joined.
+    #[doc(alias = "gnrc_netif_ipv6_group_join")]
+    pub fn join_ipv6_group(&self, group: &Address) -> Result<(), NumericError> {
+        if !group.is_multicast() {
+            return Err(crate::error::EINVAL);
+        }
+        // unsafe: C API with valid pointers (the netif is valid by construction of Netif)
+        unsafe {
+            riot_sys::inline::gnrc_netif_ipv6_group_join(
+                crate::inline_cast(self.0),
+                crate::inline_cast(group.as_ptr()),
+            )
+        }
+        .negative_to_error()
+        .map(|_| ())
+    }
+
+    /// Leave an IPv6 multicast group on the interface
+    #[doc(alias = "gnrc_netif_ipv6_group_leave")]
+    pub fn leave_ipv6_group(&self, group: &Address) -> Result<(), NumericError> {
+        // unsafe: C API with valid pointers (the netif is valid by construction of Netif)
+        unsafe {
+            riot_sys::inline::gnrc_netif_ipv6_group_leave(
+                crate::inline_cast(self.0),
+                crate::inline_cast(group.as_ptr()),
+            )
+        }
+        .negative_to_error()
+        .map(|_| ())
+    }
 }
 
 /// Helper for [super::Netif::ipv6_addrs]: As the [riot_sys::gnrc_netif_ipv6_addrs_get] function requires
@@ -55,6 +130,14 @@ impl<'a, const MAX: usize> core::iter::IntoIterator for &'a AddrList<MAX> {
     }
 }
 
+/// The list is serialized as a sequence of addresses.
+#[cfg(feature = "with_serde")]
+impl<const MAX: usize> serde::Serialize for AddrList<MAX> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
 /// An IPv6 address
 ///
 /// This is strictly equivalent and convertible with a [core::net::Ipv6Addr], but can not be
@@ -84,6 +167,18 @@ impl From<&Address> for core::net::Ipv6Addr {
     }
 }
 
+impl From<core::net::Ipv6Addr> for Address {
+    fn from(addr: core::net::Ipv6Addr) -> Self {
+        Self::from(&addr)
+    }
+}
+
+impl From<Address> for core::net::Ipv6Addr {
+    fn from(addr: Address) -> Self {
+        Self::from(&addr)
+    }
+}
+
 impl ::core::str::FromStr for Address {
     type Err = ();
 
@@ -99,6 +194,122 @@ impl ::core::fmt::Debug for Address {
     }
 }
 
+/// Addresses are serialized in their text form (eg. `"fe80::1"`) in human readable formats, and
+/// as a 16 byte string otherwise.
+///
+/// When deserializing from a compact format, a sequence of 16 numbers is accepted as well as a
+/// byte string.
+#[cfg(feature = "with_serde")]
+impl serde::Serialize for Address {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&core::net::Ipv6Addr::from(self))
+        } else {
+            serializer.serialize_bytes(self.raw())
+        }
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl<'de> serde::Deserialize<'de> for Address {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TextVisitor;
+
+        impl serde::de::Visitor<'_> for TextVisitor {
+            type Value = Address;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("an IPv6 address in text form")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Address, E> {
+                v.parse()
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+            }
+        }
+
+        struct BytesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+            type Value = Address;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("16 bytes of an IPv6 address")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Address, E> {
+                let u8_ = v
+                    .try_into()
+                    .map_err(|_| E::invalid_length(v.len(), &self))?;
+                Ok(Address {
+                    inner: ipv6_addr_t { u8_ },
+                })
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Address, A::Error> {
+                let mut u8_ = [0; 16];
+                for (i, byte) in u8_.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                }
+                if seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(17, &self));
+                }
+                Ok(Address {
+                    inner: ipv6_addr_t { u8_ },
+                })
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(TextVisitor)
+        } else {
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "with_defmt")]
+impl defmt::Format for Address {
+    /// Format the address in its canonical text form (RFC 5952)
+    ///
+    /// This is implemented on the groups directly rather than through [core::fmt], keeping the
+    /// formatting work on the host.
+    fn format(&self, f: defmt::Formatter) {
+        let groups: [u16; 8] = core::array::from_fn(|i| {
+            u16::from_be_bytes([self.raw()[2 * i], self.raw()[2 * i + 1]])
+        });
+
+        // The longest run of at least two zero groups is elided (the first one if there are ties)
+        let mut elided = 0..0;
+        let mut start = 0;
+        while start < 8 {
+            let len = groups[start..].iter().take_while(|g| **g == 0).count();
+            if len >= 2 && len > elided.len() {
+                elided = start..start + len;
+            }
+            start += len.max(1);
+        }
+
+        for (i, group) in groups.iter().enumerate() {
+            if elided.contains(&i) {
+                if i == elided.start {
+                    defmt::write!(f, "::");
+                }
+                continue;
+            }
+            if i != 0 && i != elided.end {
+                defmt::write!(f, ":");
+            }
+            defmt::write!(f, "{=u16:x}", *group);
+        }
+    }
+}
+
 impl Address {
     pub fn raw(&self) -> &[u8; 16] {
         unsafe { &self.inner.u8_ }
@@ -108,10 +319,18 @@ impl Address {
         &self.inner
     }
 
+    /// View an ipv6_addr_t as an Address, without copying it
+    ///
+    /// This is safe because any 16 bytes are a valid ipv6_addr_t (and thus Address); the borrow
+    /// keeps the address from changing while it is viewed.
+    pub fn from_ref(raw: &ipv6_addr_t) -> &Self {
+        // unsafe: Per transparency of the Address type
+        unsafe { &*(raw as *const ipv6_addr_t as *const Self) }
+    }
+
     /// Given a ipv6_addr_t, copy the data out into an Address.
     ///
-    /// That might be inefficient in many cases, and there might be a way to get an &Address
-    /// newtyped from a &ipv6_addr_t, but right now this was simple to do.
+    /// Where a reference to the ipv6_addr_t is available, [Self::from_ref] avoids the copy.
     pub fn clone_from_ptr(raw: *const ipv6_addr_t) -> Self {
         Address {
             inner: unsafe { *raw },
@@ -137,6 +356,144 @@ impl Address {
     pub fn is_link_local(&self) -> bool {
         unsafe { riot_sys::inline::ipv6_addr_is_link_local(crate::inline_cast_ref(self)) }
     }
+
+    #[doc(alias = "ipv6_addr_is_site_local")]
+    pub fn is_site_local(&self) -> bool {
+        unsafe { riot_sys::inline::ipv6_addr_is_site_local(crate::inline_cast_ref(self)) }
+    }
+
+    #[doc(alias = "ipv6_addr_is_global")]
+    pub fn is_global(&self) -> bool {
+        unsafe { riot_sys::inline::ipv6_addr_is_global(crate::inline_cast_ref(self)) }
+    }
+
+    /// The scope of the address
+    ///
+    /// Note that unique local addresses (`fc00::/7`) have global scope.
+    pub fn scope(&self) -> AddrScope {
+        if self.is_multicast() {
+            AddrScope::Multicast(self.raw()[1] & 0x0f)
+        } else if self.is_loopback() {
+            AddrScope::Loopback
+        } else if self.is_link_local() {
+            AddrScope::LinkLocal
+        } else if self.is_site_local() {
+            AddrScope::SiteLocal
+        } else if self.is_global() {
+            AddrScope::Global
+        } else {
+            AddrScope::Unspecified
+        }
+    }
+
+    /// The solicited-node multicast address (`ff02::1:ffXX:XXXX`) of the address, which is used
+    /// in neighbor discovery
+    ///
+    /// ```no_run
+    /// # use riot_wrappers::gnrc::ipv6::Address;
+    /// let addr: Address = "2001:db8::1".parse().unwrap();
+    /// let expected: Address = "ff02::1:ff00:1".parse().unwrap();
+    /// assert_eq!(addr.solicited_nodes().raw(), expected.raw());
+    /// ```
+    #[doc(alias = "ipv6_addr_set_solicited_nodes")]
+    pub fn solicited_nodes(&self) -> Address {
+        let mut out = MaybeUninit::<Address>::uninit();
+        // unsafe: C function that fully initializes the output from a valid input
+        unsafe {
+            riot_sys::inline::ipv6_addr_set_solicited_nodes(
+                crate::inline_cast_mut(out.as_mut_ptr()),
+                crate::inline_cast_ref(self),
+            );
+            out.assume_init()
+        }
+    }
+
+    /// Number of leading bits that the addresses have in common (from 0 to 128)
+    #[doc(alias = "ipv6_addr_match_prefix")]
+    pub fn common_prefix_len(&self, other: &Address) -> u8 {
+        // unsafe: Side effect free C function on valid addresses
+        unsafe { riot_sys::ipv6_addr_match_prefix(&self.inner, &other.inner) }
+    }
+
+    /// Whether the first `len` bits of the address are the same as those of the prefix
+    ///
+    /// Lengths beyond 128 behave like 128.
+    pub fn matches_prefix(&self, prefix: &Address, len: u8) -> bool {
+        self.common_prefix_len(prefix) >= len.min(128)
+    }
+}
+
+/// Scope of an [Address], as returned by [Address::scope]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AddrScope {
+    /// The unspecified address (`::`)
+    Unspecified,
+    /// The loopback address (`::1`)
+    Loopback,
+    /// A unicast address in `fe80::/10`
+    LinkLocal,
+    /// A unicast address in the deprecated site-local range `fec0::/10`
+    SiteLocal,
+    /// Any other unicast address
+    Global,
+    /// A multicast address, with the scope value given in its address (eg. 2 for link-local)
+    Multicast(u8),
+}
+
+impl AddrScope {
+    /// The scope value as used in the comparisons of [RFC 6724] (where larger is wider)
+    ///
+    /// [RFC 6724]: https://www.rfc-editor.org/rfc/rfc6724#section-3.1
+    fn value(self) -> u8 {
+        match self {
+            AddrScope::Loopback | AddrScope::LinkLocal => 0x2,
+            AddrScope::SiteLocal => 0x5,
+            AddrScope::Global | AddrScope::Unspecified => 0xe,
+            AddrScope::Multicast(scope) => scope,
+        }
+    }
+}
+
+/// Pick the source address to use towards a destination out of the candidates
+///
+/// This applies the rules of [RFC 6724 Section 5] that do not need information beyond the
+/// addresses: An address equal to the destination is preferred (rule 1), then one of appropriate
+/// scope (rule 2), and then the one sharing the longest prefix with the destination (rule 8).
+/// Unspecified and multicast candidates are never selected.
+///
+/// GNRC applies these rules itself when sending without an explicit source address; this is for
+/// applications that need to know the source address in advance.
+///
+/// [RFC 6724 Section 5]: https://www.rfc-editor.org/rfc/rfc6724#section-5
+pub fn select_source_addr<'a>(dst: &Address, candidates: &'a [Address]) -> Option<&'a Address> {
+    let dst_scope = dst.scope().value();
+    candidates
+        .iter()
+        .filter(|c| !c.is_unspecified() && !c.is_multicast())
+        .reduce(|a, b| {
+            // Rule 1: Prefer same address
+            if a.raw() == dst.raw() {
+                return a;
+            }
+            if b.raw() == dst.raw() {
+                return b;
+            }
+            // Rule 2: Prefer appropriate scope
+            let (scope_a, scope_b) = (a.scope().value(), b.scope().value());
+            if scope_a < scope_b {
+                return if scope_a < dst_scope { b } else { a };
+            }
+            if scope_b < scope_a {
+                return if scope_b < dst_scope { a } else { b };
+            }
+            // Rule 8: Use longest matching prefix
+            if b.common_prefix_len(dst) > a.common_prefix_len(dst) {
+                b
+            } else {
+                a
+            }
+        })
 }
 
 #[cfg(feature = "with_embedded_nal")]
@@ -164,6 +521,7 @@ impl From<Address> for embedded_nal::Ipv6Addr {
 /// Don't consider the error type final, that's just what works easily Right Now.
 // This is not implemented in terms of the RIOT ipv6_addr functions (ipv6_addr_split_iface) as they
 // heavily rely on null-terminated strings and mutating memory.
+#[deprecated(note = "Use split_address_netif, which also checks that the interface exists")]
 pub fn split_address(input: &str) -> Result<(Address, Option<kernel_pid_t>), &'static str> {
     let mut s = input.splitn(2, "%");
     let addr = s
@@ -179,6 +537,63 @@ pub fn split_address(input: &str) -> Result<(Address, Option<kernel_pid_t>), &'s
     Ok((addr, interface))
 }
 
+/// Given an address like fe80::1%6, split it up into an Address and the network interface with
+/// that identifier, if any is given.
+///
+/// Unlike [split_address], this also fails if the interface identifier is not the PID of a GNRC
+/// network interface.
+pub fn split_address_netif(input: &str) -> Result<(Address, Option<super::Netif>), &'static str> {
+    #[allow(deprecated)] // reason: That's just the shared implementation
+    let (addr, pid) = split_address(input)?;
+    let netif = match pid {
+        None => None,
+        Some(pid) => Some(
+            crate::thread::KernelPID::new(pid)
+                .and_then(super::Netif::by_pid)
+                .ok_or("Unknown interface")?,
+        ),
+    };
+    Ok((addr, netif))
+}
+
+/// An address along with an optional zone (interface) identifier, as in `fe80::1%6`
+///
+/// This parses and displays the text form that [split_address_netif] takes. The zone is not
+/// checked to be an existing interface.
+#[derive(Copy, Clone, Debug)]
+pub struct ScopedAddress {
+    pub addr: Address,
+    pub zone: Option<kernel_pid_t>,
+}
+
+impl ScopedAddress {
+    /// The network interface identified by the zone, if there is a zone and it is the PID of a
+    /// GNRC network interface
+    pub fn netif(&self) -> Option<super::Netif> {
+        crate::thread::KernelPID::new(self.zone?).and_then(super::Netif::by_pid)
+    }
+}
+
+impl core::str::FromStr for ScopedAddress {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[allow(deprecated)] // reason: That's just the shared implementation
+        let (addr, zone) = split_address(s)?;
+        Ok(ScopedAddress { addr, zone })
+    }
+}
+
+impl core::fmt::Display for ScopedAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", core::net::Ipv6Addr::from(&self.addr))?;
+        if let Some(zone) = self.zone {
+            write!(f, "%{}", zone)?;
+        }
+        Ok(())
+    }
+}
+
 impl<M: Mode> Pktsnip<M> {
     /// Get the IPv6 header of the snip, if there is any thusly typed snip present
     // Note that we can *not* just implement this with &mut on a Writable Pktsnip, because
@@ -226,13 +641,11 @@ pub struct Header {
 
 impl Header {
     pub fn src(&self) -> &Address {
-        // unsafe: Per transparency of the Address type
-        unsafe { core::mem::transmute(&self.inner.src) }
+        Address::from_ref(&self.inner.src)
     }
 
     pub fn dst(&self) -> &Address {
-        // unsafe: Per transparency of the Address type
-        unsafe { core::mem::transmute(&self.inner.dst) }
+        Address::from_ref(&self.inner.dst)
     }
 
     pub fn len(&self) -> u16 {
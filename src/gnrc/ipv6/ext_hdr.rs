@@ -0,0 +1,289 @@
+//! IPv6 extension headers
+//!
+//! Extension headers are built into their own snips between the IPv6 header and the upper layer
+//! snip, either one by one through [Pktsnip::ipv6_ext_hdr_build], or together with the IPv6
+//! header through [PktBuilder::ipv6_with_ext_hdrs], which also takes care of setting the Next
+//! Header fields along the chain.
+//!
+//! On received packets, GNRC marks each extension header as a snip of its own;
+//! [Pktsnip::ipv6_ext_hdrs] walks them in the order they were on the wire.
+//!
+//! A [HopByHop] header can only be the first extension header; building it in any other
+//! position panics.
+
+use riot_sys::gnrc_nettype_t_GNRC_NETTYPE_IPV6_EXT as GNRC_NETTYPE_IPV6_EXT;
+
+use super::Address;
+use crate::gnrc_pktbuf::{Mode, NotEnoughSpace, PktBuilder, Pktsnip, Writable};
+
+/// Extension headers are sized in multiples of this
+const LEN_UNIT: usize = 8;
+
+/// An extension header that can be built into a packet
+pub trait ExtHdr {
+    /// The protocol number that identifies this type of header in the preceding Next Header
+    /// field
+    fn protocol(&self) -> u8;
+
+    /// Length of the header after its Next Header and Hdr Ext Len fields
+    ///
+    /// Together with those 2 bytes, this needs to be a multiple of 8 bytes.
+    fn body_len(&self) -> usize;
+
+    /// Write the header after its Next Header and Hdr Ext Len fields into the buffer, which is
+    /// [Self::body_len] long
+    fn write_body(&self, body: &mut [u8]);
+}
+
+/// A Segment Routing Header (routing type 4, RFC 8754)
+///
+/// The segments are given in the order they are visited. The packet's IPv6 destination needs to
+/// be the first segment.
+///
+/// GNRC only builds and passes on this header: It does not process Segment Routing headers in
+/// packets it receives or forwards, so the segments need to be nodes that implement RFC 8754.
+pub struct RouteHeader<'a> {
+    segments: &'a [Address],
+}
+
+impl<'a> RouteHeader<'a> {
+    /// Create a routing header through the given segments
+    ///
+    /// ## Panics
+    ///
+    /// This panics if no segments, or more than a routing header can express, are given.
+    pub fn new(segments: &'a [Address]) -> Self {
+        assert!(
+            !segments.is_empty() && segments.len() <= 128,
+            "Routing header needs between 1 and 128 segments"
+        );
+        Self { segments }
+    }
+}
+
+impl ExtHdr for RouteHeader<'_> {
+    fn protocol(&self) -> u8 {
+        riot_sys::PROTNUM_IPV6_EXT_RH as _
+    }
+
+    fn body_len(&self) -> usize {
+        6 + 16 * self.segments.len()
+    }
+
+    fn write_body(&self, body: &mut [u8]) {
+        let last_entry = (self.segments.len() - 1) as u8;
+        // Routing type, segments left, last entry, flags, tag
+        body[..6].copy_from_slice(&[4, last_entry, last_entry, 0, 0, 0]);
+        // The segment list is stored in reverse order of visiting
+        for (chunk, segment) in body[6..]
+            .chunks_exact_mut(16)
+            .zip(self.segments.iter().rev())
+        {
+            chunk.copy_from_slice(segment.raw());
+        }
+    }
+}
+
+/// A single option of a [HopByHop] header
+pub struct HopByHopOption<'a> {
+    /// The option type, including its action and change bits
+    pub option_type: u8,
+    /// The option data, at most 255 bytes
+    pub data: &'a [u8],
+}
+
+/// A Hop-by-Hop Options header
+///
+/// It is padded as needed. This header needs to be the first after the IPv6 header.
+pub struct HopByHop<'a> {
+    pub options: &'a [HopByHopOption<'a>],
+}
+
+impl HopByHop<'_> {
+    fn unpadded_len(&self) -> usize {
+        self.options.iter().map(|o| 2 + o.data.len()).sum()
+    }
+}
+
+impl ExtHdr for HopByHop<'_> {
+    fn protocol(&self) -> u8 {
+        riot_sys::PROTNUM_IPV6_EXT_HOPOPT as _
+    }
+
+    fn body_len(&self) -> usize {
+        (2 + self.unpadded_len()).next_multiple_of(LEN_UNIT) - 2
+    }
+
+    fn write_body(&self, body: &mut [u8]) {
+        let mut cursor = 0;
+        for option in self.options {
+            let len: u8 = option
+                .data
+                .len()
+                .try_into()
+                .expect("Option data exceeds 255 bytes");
+            body[cursor] = option.option_type;
+            body[cursor + 1] = len;
+            body[cursor + 2..cursor + 2 + option.data.len()].copy_from_slice(option.data);
+            cursor += 2 + option.data.len();
+        }
+        match body.len() - cursor {
+            0 => (),
+            // Pad1
+            1 => body[cursor] = 0,
+            // PadN
+            n => {
+                body[cursor] = 1;
+                body[cursor + 1] = (n - 2) as u8;
+                body[cursor + 2..].fill(0);
+            }
+        }
+    }
+}
+
+impl<M: Mode> Pktsnip<M> {
+    /// Build an extension header in front of the snip
+    ///
+    /// The `next_header` is the protocol number of the snip this is built around.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the snip is a Hop-by-Hop Options header, which needs to come first.
+    #[doc(alias = "gnrc_ipv6_ext_build")]
+    pub fn ipv6_ext_hdr_build(
+        self,
+        next_header: u8,
+        header: &dyn ExtHdr,
+    ) -> Result<Pktsnip<Writable>, NotEnoughSpace> {
+        assert!(
+            next_header != riot_sys::PROTNUM_IPV6_EXT_HOPOPT as u8,
+            "Hop-by-Hop Options header needs to be the first extension header"
+        );
+        let size = 2 + header.body_len();
+        debug_assert!(
+            size % LEN_UNIT == 0,
+            "Extension header length is not a multiple of 8"
+        );
+        // unsafe: C API; the new snip takes over our reference to self
+        let snip = unsafe {
+            riot_sys::gnrc_ipv6_ext_build(core::ptr::null_mut(), self.ptr, next_header, size as _)
+        };
+        if snip.is_null() {
+            return Err(NotEnoughSpace);
+        }
+        core::mem::forget(self);
+        // unsafe: Freshly allocated snip
+        let mut snip = unsafe { Pktsnip::<Writable>::from_ptr(snip) };
+        // Next Header and Hdr Ext Len were set by gnrc_ipv6_ext_build
+        header.write_body(&mut snip.data_mut()[2..]);
+        Ok(snip)
+    }
+
+    /// Iterate over the IPv6 extension headers of a received packet
+    ///
+    /// Headers are produced in the order in which they follow the IPv6 header.
+    pub fn ipv6_ext_hdrs(&self) -> ExtHdrIter<'_, M> {
+        let remaining = self
+            .iter_snips()
+            .filter(|s| s.type_ == GNRC_NETTYPE_IPV6_EXT)
+            .count();
+        ExtHdrIter {
+            pkt: self,
+            remaining,
+            protocol: self.ipv6_get_header().map(|h| h.next_header()),
+        }
+    }
+}
+
+impl PktBuilder {
+    /// Build a chain of extension headers and an IPv6 header in front of what was built so far
+    ///
+    /// The headers are given in the order they follow the IPv6 header. The Next Header fields of
+    /// the IPv6 header and the extension headers are set along the chain.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if a [HopByHop] header is given in any but the first position.
+    pub fn ipv6_with_ext_hdrs(
+        self,
+        headers: &[&dyn ExtHdr],
+        src: Option<&Address>,
+        dst: Option<&Address>,
+    ) -> Self {
+        self.map(|mut pkt| {
+            // unsafe: C API on a type value
+            let mut next_header = unsafe {
+                riot_sys::inline::gnrc_nettype_to_protnum(
+                    pkt.iter_snips().next().unwrap().type_ as _,
+                )
+            } as u8;
+            for header in headers.iter().rev() {
+                pkt = pkt.ipv6_ext_hdr_build(next_header, *header)?;
+                next_header = header.protocol();
+            }
+            let mut pkt = pkt.ipv6_hdr_build(src, dst)?;
+            // unsafe: The snip was just built as an IPv6 header
+            unsafe {
+                (*(pkt.data_mut().as_mut_ptr() as *mut riot_sys::ipv6_hdr_t)).nh = next_header
+            };
+            Ok(pkt)
+        })
+    }
+}
+
+/// Iterator over the extension headers of a received packet; see [Pktsnip::ipv6_ext_hdrs]
+pub struct ExtHdrIter<'a, M: Mode> {
+    pkt: &'a Pktsnip<M>,
+    /// Number of extension header snips not yet produced, which are the first in the snip list
+    remaining: usize,
+    /// Protocol number of the next snip to be produced
+    protocol: Option<u8>,
+}
+
+impl<'a, M: Mode> Iterator for ExtHdrIter<'a, M> {
+    type Item = ExtHdrView<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        // Received headers are in reverse order in the snip list: The one following the IPv6
+        // header is the last.
+        let snip = self
+            .pkt
+            .iter_snips()
+            .filter(|s| s.type_ == GNRC_NETTYPE_IPV6_EXT)
+            .nth(self.remaining)?;
+        let view = ExtHdrView {
+            protocol: self.protocol?,
+            data: snip.data,
+        };
+        self.protocol = Some(view.next_header());
+        Some(view)
+    }
+}
+
+/// A received extension header
+#[derive(Debug)]
+pub struct ExtHdrView<'a> {
+    protocol: u8,
+    data: &'a [u8],
+}
+
+impl<'a> ExtHdrView<'a> {
+    /// The protocol number identifying the type of this header
+    pub fn protocol(&self) -> u8 {
+        self.protocol
+    }
+
+    /// The protocol number of the header that follows
+    pub fn next_header(&self) -> u8 {
+        self.data[0]
+    }
+
+    /// The header data after its Next Header and Hdr Ext Len fields
+    pub fn body(&self) -> &'a [u8] {
+        &self.data[2..]
+    }
+}
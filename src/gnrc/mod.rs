@@ -1,16 +1,34 @@
+#[cfg(all(riot_module_gnrc_netapi_callbacks, riot_module_gnrc_pktbuf))]
+pub mod filter;
 #[cfg(riot_module_gnrc_icmpv6)]
 pub mod icmpv6;
 #[cfg(riot_module_ipv6)]
 pub mod ipv6;
 pub mod netif;
+#[cfg(all(riot_module_gnrc_netif_bus, riot_module_ipv6, riot_module_core_msg_bus))]
+pub mod netif_event;
 
 pub mod netapi;
 pub mod netreg;
 #[cfg(riot_module_gnrc_ipv6_nib)]
 pub mod nib;
+#[cfg(riot_module_gnrc_pktbuf)]
+pub mod pktdump;
+#[cfg(all(riot_module_gnrc_rpl, riot_module_ipv6))]
+pub mod rpl;
+pub mod sixlowpan;
+#[cfg(all(
+    riot_module_gnrc_udp,
+    riot_module_udp,
+    riot_module_ipv6,
+    riot_module_gnrc_pktbuf,
+    riot_module_core_msg
+))]
+pub mod udp;
 
 use riot_sys::{gnrc_netif_iter, gnrc_netif_t};
 
+use crate::error::{NegativeErrorExt, NumericError};
 use crate::thread::KernelPID;
 use core::iter::Iterator;
 
@@ -91,6 +109,15 @@ impl Netif {
         .map(Netif)
     }
 
+    /// Iterate over all interfaces whose device is of the given type
+    ///
+    /// The type is queried from each interface through [Self::device_type], so (like all netapi
+    /// calls) this must not be called from any network interface's thread. Interfaces that fail
+    /// to report a type are skipped.
+    pub fn iter_by_type(device_type: NetdevType) -> impl Iterator<Item = Netif> {
+        Self::all().filter(move |netif| netif.device_type().ok() == Some(device_type))
+    }
+
     #[doc(alias = "gnrc_netif_get_by_pid")]
     pub fn by_pid(pid: KernelPID) -> Option<Self> {
         const NULL: *mut riot_sys::gnrc_netif_t = core::ptr::null_mut();
@@ -103,6 +130,27 @@ impl Netif {
         }
     }
 
+    /// Find an interface by its name (eg. `"4"` or `"ethernet0"`, as displayed by `ifconfig`)
+    ///
+    /// To find an interface by its PID instead, use [Self::by_pid].
+    #[doc(alias = "netif_get_by_name")]
+    pub fn find_by_name(name: &str) -> Option<Self> {
+        // Large enough for any name netif_get_name produces, and the nul terminator
+        let mut buf = [0u8; riot_sys::CONFIG_NETIF_NAMELENMAX as usize + 1];
+        if name.len() >= buf.len() || name.as_bytes().contains(&0) {
+            return None;
+        }
+        buf[..name.len()].copy_from_slice(name.as_bytes());
+
+        // unsafe: C API with a nul terminated string
+        let netif = unsafe { riot_sys::netif_get_by_name(buf.as_ptr() as _) };
+        if netif.is_null() {
+            return None;
+        }
+        // With GNRC, all interfaces are GNRC interfaces, whose netif_t is their first member
+        Some(Netif(netif as *const gnrc_netif_t))
+    }
+
     pub fn pid(&self) -> KernelPID {
         KernelPID(unsafe { (*self.0).pid })
     }
@@ -110,4 +158,749 @@ impl Netif {
     pub fn l2addr(&self) -> &[u8] {
         unsafe { &(*self.0).l2addr[..(*self.0).l2addr_len as usize] }
     }
+
+    /// Read an option from the interface into the buffer, returning the length written
+    ///
+    /// The data format depends on the option, and is described in the documentation of the
+    /// `netopt_t` values; typically, numbers are in host byte order and of the documented size.
+    /// Prefer the typed accessors (like [Self::channel]) where they are available.
+    ///
+    /// This goes through the netapi and thus blocks until the interface's thread responded; it
+    /// must not be called from that thread.
+    #[doc(alias = "gnrc_netapi_get")]
+    pub fn get_opt(&self, opt: Netopt, buf: &mut [u8]) -> Result<usize, NumericError> {
+        self.get_opt_with_context(opt, 0, buf)
+    }
+
+    /// Like [Self::get_opt], but for options that take a context value (eg. the layer whose
+    /// statistics are to be read)
+    fn get_opt_with_context(
+        &self,
+        opt: Netopt,
+        context: u16,
+        buf: &mut [u8],
+    ) -> Result<usize, NumericError> {
+        // unsafe: C API with a valid buffer whose length is passed along
+        (unsafe {
+            riot_sys::inline::gnrc_netapi_get(
+                self.pid().into(),
+                opt.0 as _,
+                context,
+                buf.as_mut_ptr() as _,
+                buf.len() as _,
+            )
+        })
+        .negative_to_error()
+        .map(|len| len as _)
+    }
+
+    /// Write an option to the interface from the buffer
+    ///
+    /// Like [Self::get_opt], this blocks until the interface's thread responded. Options that do
+    /// not have the expected size are rejected by the interface.
+    #[doc(alias = "gnrc_netapi_set")]
+    pub fn set_opt(&self, opt: Netopt, data: &[u8]) -> Result<(), NumericError> {
+        // unsafe: C API with a valid buffer whose length is passed along
+        (unsafe {
+            riot_sys::inline::gnrc_netapi_set(
+                self.pid().into(),
+                opt.0 as _,
+                0,
+                data.as_ptr() as _,
+                data.len() as _,
+            )
+        })
+        .negative_to_error()
+        .map(|_| ())
+    }
+
+    /// Read an option whose value is a single number (or `netopt_*_t` enum value)
+    ///
+    /// The value is read into a variable of its type rather than into a byte array, as drivers
+    /// access the buffer through pointers to that type, which requires proper alignment. Values
+    /// of any other length than the type's produce an `ENOTSUP` error.
+    fn get_opt_value<T: OptValue>(&self, opt: Netopt) -> Result<T, NumericError> {
+        let mut value = T::default();
+        match self.get_opt(opt, value_bytes_mut(&mut value))? {
+            len if len == core::mem::size_of::<T>() => Ok(value),
+            _ => Err(NumericError::from_constant(riot_sys::ENOTSUP as _)),
+        }
+    }
+
+    /// Write an option whose value is a single number (or `netopt_*_t` enum value), see
+    /// [Self::get_opt_value]
+    fn set_opt_value<T: OptValue>(&self, opt: Netopt, value: T) -> Result<(), NumericError> {
+        self.set_opt(opt, value_bytes(&value))
+    }
+
+    /// The interface's long (EUI-64) hardware address
+    ///
+    /// This is available on IEEE 802.15.4 interfaces, which also have a short (16-bit) address,
+    /// and on other interfaces that use 8 byte addresses. Ethernet-like interfaces, which only
+    /// have a 48-bit address, produce an error; see [Self::mac_address] for those.
+    #[doc(alias = "NETOPT_ADDRESS_LONG")]
+    pub fn mac_address_long(&self) -> Result<[u8; 8], NumericError> {
+        let mut addr = [0; 8];
+        match self.get_opt(Netopt::ADDRESS_LONG, &mut addr)? {
+            8 => Ok(addr),
+            _ => Err(NumericError::from_constant(riot_sys::ENOTSUP as _)),
+        }
+    }
+
+    /// The interface's 48-bit (EUI-48) hardware address
+    ///
+    /// This is available on Ethernet, WiFi and BLE interfaces. Interfaces whose primary address
+    /// has a different length (eg. the 16-bit short address of IEEE 802.15.4 interfaces) produce
+    /// an error; see [Self::mac_address_long] for those.
+    #[doc(alias = "NETOPT_ADDRESS")]
+    pub fn mac_address(&self) -> Result<[u8; 6], NumericError> {
+        // Large enough for any address the option may produce
+        let mut addr = [0; 8];
+        match self.get_opt(Netopt::ADDRESS, &mut addr)? {
+            6 => Ok(addr[..6].try_into().unwrap()),
+            _ => Err(NumericError::from_constant(riot_sys::ENOTSUP as _)),
+        }
+    }
+
+    /// The interface's primary hardware address, in whichever length the interface uses
+    ///
+    /// On IEEE 802.15.4 interfaces, this is the 2 byte short address; on Ethernet-like interfaces,
+    /// the 6 byte MAC address.
+    #[doc(alias = "NETOPT_ADDRESS")]
+    pub fn hw_addr(&self) -> Result<HwAddr, NumericError> {
+        self.get_hw_addr(Netopt::ADDRESS)
+    }
+
+    /// The interface's long hardware address, in whichever length the interface uses
+    ///
+    /// Unlike [Self::mac_address_long], this does not insist on an 8 byte result.
+    #[doc(alias = "NETOPT_ADDRESS_LONG")]
+    pub fn hw_addr_long(&self) -> Result<HwAddr, NumericError> {
+        self.get_hw_addr(Netopt::ADDRESS_LONG)
+    }
+
+    /// The IEEE 802.15.4 short address of the interface
+    ///
+    /// The address is read in the byte order in which it is shown (eg. `12:34` is `0x1234`).
+    /// Interfaces whose hardware address is not 2 bytes long produce an `ENOTSUP` error.
+    #[doc(alias = "NETOPT_ADDRESS")]
+    pub fn short_addr(&self) -> Result<u16, NumericError> {
+        let mut addr = [0; 2];
+        match self.get_opt(Netopt::ADDRESS, &mut addr) {
+            Ok(2) => Ok(u16::from_be_bytes(addr)),
+            // Longer addresses do not fit the buffer
+            Ok(_) | Err(crate::error::EOVERFLOW) => {
+                Err(NumericError::from_constant(riot_sys::ENOTSUP as _))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Set the IEEE 802.15.4 short address of the interface (see [Self::short_addr] on the byte
+    /// order)
+    #[doc(alias = "NETOPT_ADDRESS")]
+    pub fn set_short_addr(&self, addr: u16) -> Result<(), NumericError> {
+        self.set_opt(Netopt::ADDRESS, &addr.to_be_bytes())
+    }
+
+    /// The IEEE 802.15.4 PAN ID of the interface
+    ///
+    /// Interfaces without a PAN ID (or other 2 byte network ID) produce an `ENOTSUP` error.
+    #[doc(alias = "NETOPT_NID")]
+    pub fn pan_id(&self) -> Result<u16, NumericError> {
+        self.get_opt_value(Netopt::NID)
+    }
+
+    /// Set the IEEE 802.15.4 PAN ID of the interface
+    #[doc(alias = "NETOPT_NID")]
+    pub fn set_pan_id(&self, pan_id: u16) -> Result<(), NumericError> {
+        self.set_opt_value(Netopt::NID, pan_id)
+    }
+
+    fn get_hw_addr(&self, opt: Netopt) -> Result<HwAddr, NumericError> {
+        let mut addr = [0; HwAddr::MAX_LEN];
+        let len = self.get_opt(opt, &mut addr)?;
+        Ok(HwAddr::from_slice(&addr[..len]).expect("Driver wrote more than the buffer size"))
+    }
+
+    /// The type of device the interface is backed by
+    #[doc(alias = "NETOPT_DEVICE_TYPE")]
+    pub fn device_type(&self) -> Result<NetdevType, NumericError> {
+        let device_type: u16 = self.get_opt_value(Netopt::DEVICE_TYPE)?;
+        Ok(device_type.into())
+    }
+
+    /// The transmit power of the interface in dBm
+    ///
+    /// Like all NETOPT values, this is exchanged with the driver in the host's byte order.
+    #[doc(alias = "NETOPT_TX_POWER")]
+    pub fn tx_power(&self) -> Result<i16, NumericError> {
+        self.get_opt_value(Netopt::TX_POWER)
+    }
+
+    /// Set the transmit power of the interface in dBm
+    ///
+    /// Radios round the value to a power level they support, or reject values that are out of
+    /// their range.
+    #[doc(alias = "NETOPT_TX_POWER")]
+    pub fn set_tx_power(&self, dbm: i16) -> Result<(), NumericError> {
+        self.set_opt_value(Netopt::TX_POWER, dbm)
+    }
+
+    /// The radio channel the interface operates on
+    #[doc(alias = "NETOPT_CHANNEL")]
+    pub fn channel(&self) -> Result<u16, NumericError> {
+        self.get_opt_value(Netopt::CHANNEL)
+    }
+
+    /// Switch the interface to a different radio channel
+    ///
+    /// The channel is passed to the driver unmodified: Channels the radio does not support are
+    /// rejected with the driver's error (typically `EINVAL`) rather than being clamped.
+    #[doc(alias = "NETOPT_CHANNEL")]
+    pub fn set_channel(&self, channel: u16) -> Result<(), NumericError> {
+        self.set_opt_value(Netopt::CHANNEL, channel)
+    }
+
+    /// The largest link layer payload the interface can send in a single frame
+    ///
+    /// This is the device's limit, which is what packets sent through [Self::send_pkt] are
+    /// subject to; the IPv6 MTU of an interface that uses 6LoWPAN fragmentation can be larger.
+    #[doc(alias = "NETOPT_MAX_PDU_SIZE")]
+    pub fn mtu(&self) -> Result<u16, NumericError> {
+        self.get_opt_value(Netopt::MAX_PDU_SIZE)
+    }
+
+    /// Whether the interface is up, ie. its state is anything but off
+    ///
+    /// Note that interfaces that are merely asleep are considered up; see [Self::set_up].
+    #[doc(alias = "NETOPT_STATE")]
+    pub fn is_up(&self) -> Result<bool, NumericError> {
+        let state: riot_sys::netopt_state_t = self.get_opt_value(Netopt::STATE)?;
+        Ok(state != riot_sys::netopt_state_t_NETOPT_STATE_OFF)
+    }
+
+    /// Bring an interface up (into the idle state, where it listens for packets), or put it to
+    /// sleep
+    ///
+    /// A sleeping interface can neither send nor receive, but is woken up when a packet is sent
+    /// through it.
+    #[doc(alias = "NETOPT_STATE")]
+    pub fn set_up(&self, up: bool) -> Result<(), NumericError> {
+        let state: riot_sys::netopt_state_t = match up {
+            true => riot_sys::netopt_state_t_NETOPT_STATE_IDLE,
+            false => riot_sys::netopt_state_t_NETOPT_STATE_SLEEP,
+        };
+        self.set_opt_value(Netopt::STATE, state)
+    }
+
+    /// The interface's flags, as configured by GNRC and the interface's driver
+    pub fn flags(&self) -> NetifFlags {
+        NetifFlags(unsafe { (*self.0).flags })
+    }
+
+    /// Packet counters of the interface's link layer
+    ///
+    /// This is only available if the interface's statistics are enabled (through the
+    /// `netstats_l2` module); otherwise, an error (typically `ENOTSUP`) is returned.
+    #[doc(alias = "NETOPT_STATS")]
+    pub fn stats(&self) -> Result<NetifStats, NumericError> {
+        let mut stats = riot_sys::netstats_t::default();
+        // unsafe: The struct consists of plain integers, so any bytes are valid in it
+        let buf = unsafe {
+            core::slice::from_raw_parts_mut(
+                &mut stats as *mut _ as *mut u8,
+                core::mem::size_of::<riot_sys::netstats_t>(),
+            )
+        };
+        // The interface copies its statistics into the buffer
+        let len = self.get_opt_with_context(Netopt::STATS, riot_sys::NETSTATS_LAYER2 as _, buf)?;
+        if len != core::mem::size_of::<riot_sys::netstats_t>() {
+            return Err(NumericError::from_constant(riot_sys::ENOTSUP as _));
+        }
+        Ok(NetifStats {
+            tx_bytes: stats.tx_bytes,
+            rx_bytes: stats.rx_bytes,
+            tx_packets: stats.tx_unicast_count.wrapping_add(stats.tx_mcast_count),
+            tx_unicast: stats.tx_unicast_count,
+            tx_multicast: stats.tx_mcast_count,
+            rx_packets: stats.rx_count,
+            tx_success: stats.tx_success,
+            tx_errors: stats.tx_failed,
+        })
+    }
+
+    /// Enable or disable forwarding of IPv6 packets on the interface
+    ///
+    /// This is only supported if GNRC was built with router support (`gnrc_ipv6_router`);
+    /// otherwise, the interface rejects the setting.
+    #[doc(alias = "NETOPT_IPV6_FORWARDING")]
+    pub fn set_ipv6_forwarding(&self, enabled: bool) -> Result<(), NumericError> {
+        let value: riot_sys::netopt_enable_t = match enabled {
+            true => riot_sys::netopt_enable_t_NETOPT_ENABLE,
+            false => riot_sys::netopt_enable_t_NETOPT_DISABLE,
+        };
+        self.set_opt_value(Netopt::IPV6_FORWARDING, value)
+    }
+
+    /// Perform a clear channel assessment (CCA) on the current channel
+    ///
+    /// This returns true if the radio found the channel idle. Depending on the radio's CCA mode,
+    /// that means that the energy on the channel was below the radio's threshold, or that no
+    /// IEEE 802.15.4 signal was detected.
+    #[doc(alias = "NETOPT_IS_CHANNEL_CLR")]
+    pub fn channel_clear(&self) -> Result<bool, NumericError> {
+        let clear: riot_sys::netopt_enable_t = self.get_opt_value(Netopt::IS_CHANNEL_CLR)?;
+        Ok(clear == riot_sys::netopt_enable_t_NETOPT_ENABLE)
+    }
+
+    /// The received signal strength in dBm
+    ///
+    /// With most radios, this is the strength at which the last frame was received, rather than
+    /// a measurement of the current energy on the channel.
+    #[doc(alias = "NETOPT_RSSI")]
+    pub fn rssi(&self) -> Result<i16, NumericError> {
+        // Drivers disagree on the size of the value; an aligned i16 can hold either.
+        let mut rssi: i16 = 0;
+        match self.get_opt(Netopt::RSSI, value_bytes_mut(&mut rssi))? {
+            1 => Ok((value_bytes(&rssi)[0] as i8).into()),
+            2 => Ok(rssi),
+            _ => Err(NumericError::from_constant(riot_sys::ENOTSUP as _)),
+        }
+    }
+
+    /// Read the [signal strength](Self::rssi) on each of the channels from `from` to `to`
+    /// (inclusive)
+    ///
+    /// The iterator switches the interface's channel as it proceeds, and switches back to the
+    /// original channel when dropped. Channels the radio can not switch to, or on which it does
+    /// not report an RSSI value, are skipped. An interface that has no channel at all produces no
+    /// readings.
+    ///
+    /// As many radios report the strength of the last received frame, a reading can stem from the
+    /// previous channel if no frame was received in between; combine it with
+    /// [Self::channel_clear] where an assessment of the current energy is needed.
+    pub fn scan_channels(&self, from: u8, to: u8) -> ChannelScan<'_> {
+        ChannelScan {
+            netif: self,
+            original: self.channel().ok(),
+            channels: from..=to,
+        }
+    }
+}
+
+/// Iterator over the channels of a [Netif::scan_channels] call, producing each channel along with
+/// its RSSI reading in dBm
+pub struct ChannelScan<'a> {
+    netif: &'a Netif,
+    /// Channel to restore; None if the interface has no channel, and nothing is scanned
+    original: Option<u16>,
+    channels: core::ops::RangeInclusive<u8>,
+}
+
+impl Iterator for ChannelScan<'_> {
+    type Item = (u8, i16);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.original?;
+        loop {
+            let channel = self.channels.next()?;
+            let rssi = self
+                .netif
+                .set_channel(channel.into())
+                .and_then(|()| self.netif.rssi());
+            if let Ok(rssi) = rssi {
+                return Some((channel, rssi));
+            }
+        }
+    }
+}
+
+impl Drop for ChannelScan<'_> {
+    fn drop(&mut self) {
+        if let Some(original) = self.original {
+            // Errors are ignored: The original channel did work before the scan.
+            let _ = self.netif.set_channel(original);
+        }
+    }
+}
+
+/// Bring up the first wireless interface on a given channel and PAN ID, and print its link-local
+/// addresses
+///
+/// This is a convenience for demos and tests, which often start with this sequence. The interface
+/// picked is the first one that has a radio channel and is not an Ethernet interface; it is
+/// returned for further configuration. If there is none, `ENODEV` is returned.
+///
+/// Like all netapi calls, this must not be called from a network interface's thread.
+#[cfg(riot_module_ipv6)]
+pub fn quickstart(channel: u16, pan_id: u16) -> Result<Netif, NumericError> {
+    let netif = Netif::all()
+        .find(|netif| {
+            netif.device_type().ok() != Some(NetdevType::Ethernet) && netif.channel().is_ok()
+        })
+        .ok_or(NumericError::from_constant(riot_sys::ENODEV as _))?;
+
+    netif.set_channel(channel)?;
+    netif.set_pan_id(pan_id)?;
+
+    for addr in &netif.ipv6_addrs()? {
+        if addr.is_link_local() {
+            crate::println!("Interface {}: {:?}", netif.pid().0, addr);
+        }
+    }
+
+    Ok(netif)
+}
+
+/// Types of option values that [Netif::get_opt_value] and [Netif::set_opt_value] can exchange
+///
+/// # Safety
+///
+/// Any byte pattern needs to be a valid value of the type.
+unsafe trait OptValue: Copy + Default {}
+
+// unsafe: Plain integers; the netopt_*_t enums are expressed as one of these by bindgen.
+unsafe impl OptValue for u8 {}
+unsafe impl OptValue for i8 {}
+unsafe impl OptValue for u16 {}
+unsafe impl OptValue for i16 {}
+unsafe impl OptValue for u32 {}
+unsafe impl OptValue for i32 {}
+
+fn value_bytes<T: OptValue>(value: &T) -> &[u8] {
+    // unsafe: Viewing a plain value as its bytes (for the duration of the borrow)
+    unsafe {
+        core::slice::from_raw_parts(value as *const T as *const u8, core::mem::size_of::<T>())
+    }
+}
+
+fn value_bytes_mut<T: OptValue>(value: &mut T) -> &mut [u8] {
+    // unsafe: Viewing a plain value as its bytes, through which any value can be written
+    // (guaranteed by OptValue)
+    unsafe {
+        core::slice::from_raw_parts_mut(value as *mut T as *mut u8, core::mem::size_of::<T>())
+    }
+}
+
+/// An option that can be read from or written to a [Netif] through [Netif::get_opt] and
+/// [Netif::set_opt]
+///
+/// Common options are available as constants; any other `netopt_t` can be wrapped directly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Netopt(pub riot_sys::netopt_t);
+
+impl Netopt {
+    /// Hardware address of the interface (the short one on IEEE 802.15.4)
+    pub const ADDRESS: Self = Netopt(riot_sys::netopt_t_NETOPT_ADDRESS);
+    /// Long hardware address of the interface (on IEEE 802.15.4)
+    pub const ADDRESS_LONG: Self = Netopt(riot_sys::netopt_t_NETOPT_ADDRESS_LONG);
+    /// Radio channel (`u16`)
+    pub const CHANNEL: Self = Netopt(riot_sys::netopt_t_NETOPT_CHANNEL);
+    /// Type of the device (`u16` holding a `NETDEV_TYPE_*` value)
+    pub const DEVICE_TYPE: Self = Netopt(riot_sys::netopt_t_NETOPT_DEVICE_TYPE);
+    /// Whether IPv6 forwarding is enabled (`netopt_enable_t`)
+    pub const IPV6_FORWARDING: Self = Netopt(riot_sys::netopt_t_NETOPT_IPV6_FORWARDING);
+    /// Maximum size of a packet's payload on the link layer (`u16`)
+    pub const MAX_PDU_SIZE: Self = Netopt(riot_sys::netopt_t_NETOPT_MAX_PDU_SIZE);
+    /// Network ID, eg. the PAN ID on IEEE 802.15.4 (`u16`)
+    pub const NID: Self = Netopt(riot_sys::netopt_t_NETOPT_NID);
+    /// Whether the channel is clear, performing a CCA on reading (`netopt_enable_t`)
+    pub const IS_CHANNEL_CLR: Self = Netopt(riot_sys::netopt_t_NETOPT_IS_CHANNEL_CLR);
+    /// Whether the interface is in promiscuous mode (`netopt_enable_t`)
+    pub const PROMISCUOUSMODE: Self = Netopt(riot_sys::netopt_t_NETOPT_PROMISCUOUSMODE);
+    /// Received signal strength in dBm (`int16_t` or `int8_t`, depending on the driver)
+    pub const RSSI: Self = Netopt(riot_sys::netopt_t_NETOPT_RSSI);
+    /// Number of transmission retries on the link layer (`u8`)
+    pub const RETRANS: Self = Netopt(riot_sys::netopt_t_NETOPT_RETRANS);
+    /// State of the interface (`netopt_state_t`)
+    pub const STATE: Self = Netopt(riot_sys::netopt_t_NETOPT_STATE);
+    /// Packet counters (`netstats_t`, with the layer as context)
+    pub const STATS: Self = Netopt(riot_sys::netopt_t_NETOPT_STATS);
+    /// Transmit power in dBm (`i16`)
+    pub const TX_POWER: Self = Netopt(riot_sys::netopt_t_NETOPT_TX_POWER);
+}
+
+impl From<riot_sys::netopt_t> for Netopt {
+    fn from(opt: riot_sys::netopt_t) -> Self {
+        Netopt(opt)
+    }
+}
+
+/// The kind of device backing a network interface, see [Netif::device_type]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NetdevType {
+    Ethernet,
+    Ieee802154,
+    /// Bluetooth Low Energy, eg. through NimBLE
+    BleNimble,
+    CC110x,
+    SlipDev,
+    /// Any other `NETDEV_TYPE_*` value (including `NETDEV_TYPE_UNKNOWN`)
+    Unknown(u16),
+}
+
+impl From<u16> for NetdevType {
+    fn from(value: u16) -> Self {
+        [
+            NetdevType::Ethernet,
+            NetdevType::Ieee802154,
+            NetdevType::BleNimble,
+            NetdevType::CC110x,
+            NetdevType::SlipDev,
+        ]
+        .into_iter()
+        .find(|t| u16::from(*t) == value)
+        .unwrap_or(NetdevType::Unknown(value))
+    }
+}
+
+impl From<NetdevType> for u16 {
+    fn from(value: NetdevType) -> Self {
+        (match value {
+            NetdevType::Ethernet => riot_sys::NETDEV_TYPE_ETHERNET,
+            NetdevType::Ieee802154 => riot_sys::NETDEV_TYPE_IEEE802154,
+            NetdevType::BleNimble => riot_sys::NETDEV_TYPE_BLE,
+            NetdevType::CC110x => riot_sys::NETDEV_TYPE_CC110X,
+            NetdevType::SlipDev => riot_sys::NETDEV_TYPE_SLIP,
+            NetdevType::Unknown(value) => return value,
+        }) as _
+    }
+}
+
+/// Packet counters of a network interface, see [Netif::stats]
+///
+/// All counters wrap around on overflow. RIOT does not count receive errors; packets that fail
+/// to be received are not reflected in any of the counters.
+#[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
+pub struct NetifStats {
+    /// Number of bytes sent
+    pub tx_bytes: u32,
+    /// Number of bytes received
+    pub rx_bytes: u32,
+    /// Number of unicast and multicast packets sent
+    pub tx_packets: u32,
+    /// Number of unicast packets sent
+    pub tx_unicast: u32,
+    /// Number of multicast (and broadcast) packets sent
+    pub tx_multicast: u32,
+    /// Number of packets received
+    pub rx_packets: u32,
+    /// Number of packets whose transmission was confirmed by the driver (eg. through an ACK)
+    pub tx_success: u32,
+    /// Number of packets whose transmission failed
+    pub tx_errors: u32,
+}
+
+/// The `flags` field of a network interface, see [Netif::flags]
+///
+/// This is a snapshot of the flags at the time they were read; changes to the interface are not
+/// reflected in it.
+///
+/// Flags can be combined and tested with the bit operators, using the associated constants:
+///
+/// ```no_run
+/// # use riot_wrappers::gnrc::NetifFlags;
+/// # let netif: riot_wrappers::gnrc::Netif = unimplemented!();
+/// let router = NetifFlags::IPV6_FORWARDING | NetifFlags::IPV6_RTR_ADV;
+/// if netif.flags().contains(router) {
+///     // ...
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct NetifFlags(u32);
+
+impl NetifFlags {
+    /// No flags set
+    pub const EMPTY: Self = NetifFlags(0);
+    #[doc(alias = "GNRC_NETIF_FLAGS_HAS_L2ADDR")]
+    pub const HAS_L2ADDR: Self = NetifFlags(riot_sys::GNRC_NETIF_FLAGS_HAS_L2ADDR);
+    #[doc(alias = "GNRC_NETIF_FLAGS_IPV6_FORWARDING")]
+    pub const IPV6_FORWARDING: Self = NetifFlags(riot_sys::GNRC_NETIF_FLAGS_IPV6_FORWARDING);
+    #[doc(alias = "GNRC_NETIF_FLAGS_IPV6_RTR_ADV")]
+    pub const IPV6_RTR_ADV: Self = NetifFlags(riot_sys::GNRC_NETIF_FLAGS_IPV6_RTR_ADV);
+    /// The `GNRC_NETIF_FLAGS_6LN` flag (renamed, as identifiers can not start with a digit)
+    #[doc(alias = "GNRC_NETIF_FLAGS_6LN")]
+    pub const SIXLN: Self = NetifFlags(riot_sys::GNRC_NETIF_FLAGS_6LN);
+    #[doc(alias = "GNRC_NETIF_FLAGS_6LO_HC")]
+    pub const SIXLO_HC: Self = NetifFlags(riot_sys::GNRC_NETIF_FLAGS_6LO_HC);
+    #[doc(alias = "GNRC_NETIF_FLAGS_6LO_ABR")]
+    pub const SIXLO_ABR: Self = NetifFlags(riot_sys::GNRC_NETIF_FLAGS_6LO_ABR);
+    #[doc(alias = "GNRC_NETIF_FLAGS_RAWMODE")]
+    pub const RAWMODE: Self = NetifFlags(riot_sys::GNRC_NETIF_FLAGS_RAWMODE);
+
+    /// The raw bitmask of `GNRC_NETIF_FLAGS_*` values
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Flags from a raw bitmask of `GNRC_NETIF_FLAGS_*` values
+    pub const fn from_bits(bits: u32) -> Self {
+        NetifFlags(bits)
+    }
+
+    /// Whether all flags set in `other` are also set in `self`
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether no flag is set
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether the interface has a link-layer address
+    #[doc(alias = "GNRC_NETIF_FLAGS_HAS_L2ADDR")]
+    pub fn has_l2addr(&self) -> bool {
+        self.contains(Self::HAS_L2ADDR)
+    }
+
+    /// Whether IPv6 packets are forwarded to and from this interface
+    #[doc(alias = "GNRC_NETIF_FLAGS_IPV6_FORWARDING")]
+    pub fn is_ipv6_forwarding(&self) -> bool {
+        self.contains(Self::IPV6_FORWARDING)
+    }
+
+    /// Whether the interface sends router advertisements
+    #[doc(alias = "GNRC_NETIF_FLAGS_IPV6_RTR_ADV")]
+    pub fn is_router_advertising(&self) -> bool {
+        self.contains(Self::IPV6_RTR_ADV)
+    }
+
+    /// Whether the interface acts as a router
+    ///
+    /// As in GNRC's `gnrc_netif_is_rtr()`, this is equivalent to IPv6 forwarding being enabled.
+    pub fn is_router(&self) -> bool {
+        self.is_ipv6_forwarding()
+    }
+
+    /// Whether the interface is a 6LoWPAN node (6LN)
+    #[doc(alias = "GNRC_NETIF_FLAGS_6LN")]
+    pub fn is_6lo(&self) -> bool {
+        self.contains(Self::SIXLN)
+    }
+
+    /// Whether 6LoWPAN header compression is in use on the interface
+    #[doc(alias = "GNRC_NETIF_FLAGS_6LO_HC")]
+    pub fn is_6lo_hc(&self) -> bool {
+        self.contains(Self::SIXLO_HC)
+    }
+
+    /// Whether the interface acts as a 6LoWPAN border router (6LBR)
+    #[doc(alias = "GNRC_NETIF_FLAGS_6LO_ABR")]
+    pub fn is_6lo_abr(&self) -> bool {
+        self.contains(Self::SIXLO_ABR)
+    }
+
+    /// Whether the interface is in raw mode, passing packets up without any processing
+    #[doc(alias = "GNRC_NETIF_FLAGS_RAWMODE")]
+    pub fn is_rawmode(&self) -> bool {
+        self.contains(Self::RAWMODE)
+    }
+}
+
+impl core::ops::BitOr for NetifFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        NetifFlags(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for NetifFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl core::ops::BitAnd for NetifFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        NetifFlags(self.0 & rhs.0)
+    }
+}
+
+impl core::ops::BitAndAssign for NetifFlags {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl core::ops::BitXor for NetifFlags {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        NetifFlags(self.0 ^ rhs.0)
+    }
+}
+
+impl core::ops::BitXorAssign for NetifFlags {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0;
+    }
+}
+
+impl core::ops::Not for NetifFlags {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        NetifFlags(!self.0)
+    }
+}
+
+impl core::fmt::Debug for NetifFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "NetifFlags({:#010x})", self.0)
+    }
+}
+
+/// A hardware address of a length that depends on the interface, see [Netif::hw_addr]
+///
+/// Its [Display](core::fmt::Display) implementation produces the customary colon-separated
+/// hexadecimal form (eg. `02:00:5e:10:00:00:00:01`).
+#[derive(Clone, PartialEq, Eq)]
+pub struct HwAddr(heapless::Vec<u8, { HwAddr::MAX_LEN }>);
+
+impl HwAddr {
+    /// Length of the longest address that can be represented
+    pub const MAX_LEN: usize = 8;
+
+    /// Copy an address from a slice, eg. from [Netif::l2addr], failing if it is too long
+    pub fn from_slice(addr: &[u8]) -> Option<Self> {
+        heapless::Vec::from_slice(addr).ok().map(HwAddr)
+    }
+
+    /// The address's bytes
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl core::ops::Deref for HwAddr {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for HwAddr {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(":")?;
+            }
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for HwAddr {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "HwAddr({})", self)
+    }
 }
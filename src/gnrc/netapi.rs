@@ -3,6 +3,7 @@
 use riot_sys::gnrc_nettype_t;
 
 use crate::gnrc_pktbuf::{Pktsnip, Shared};
+use crate::thread::KernelPID;
 
 /// Dispatch a packet to all listeners of the given nettype and demux context.
 ///
@@ -23,3 +24,54 @@ pub fn dispatch_send(
     }
     subscribers
 }
+
+/// Error type of [send]
+#[derive(Debug)]
+pub enum SendError {
+    /// The recipient did not take the packet (eg. because its message queue was full)
+    NoSubscriber,
+    /// The recipient PID does not belong to a running thread
+    InvalidPID,
+}
+
+/// Send a packet to the GNRC thread of the given PID.
+///
+/// The packet is consumed in any case: Its ownership is passed on to the recipient, or it is
+/// released if it could not be delivered.
+#[doc(alias = "gnrc_netapi_send")]
+pub fn send(pid: KernelPID, pkt: impl Into<Pktsnip<Shared>>) -> Result<(), SendError> {
+    let pkt = unsafe { pkt.into().to_ptr() };
+    let result =
+        unsafe { riot_sys::inline::gnrc_netapi_send(pid.into(), crate::inline_cast_mut(pkt)) };
+    if result < 1 {
+        // Unlike gnrc_netapi_dispatch_send, this does not release the packet on failure
+        unsafe { riot_sys::inline::gnrc_pktbuf_release(crate::inline_cast_mut(pkt)) };
+    }
+    match result {
+        1.. => Ok(()),
+        0 => Err(SendError::NoSubscriber),
+        // msg_send's only error return
+        _ => Err(SendError::InvalidPID),
+    }
+}
+
+/// Hand a received packet to the GNRC thread of the given PID, as the network stack does when it
+/// dispatches incoming packets.
+///
+/// As with [send], the packet is consumed in any case.
+#[doc(alias = "gnrc_netapi_receive")]
+pub fn receive(pid: KernelPID, pkt: impl Into<Pktsnip<Shared>>) -> Result<(), SendError> {
+    let pkt = unsafe { pkt.into().to_ptr() };
+    let result =
+        unsafe { riot_sys::inline::gnrc_netapi_receive(pid.into(), crate::inline_cast_mut(pkt)) };
+    if result < 1 {
+        // As with gnrc_netapi_send, the packet is not released on failure
+        unsafe { riot_sys::inline::gnrc_pktbuf_release(crate::inline_cast_mut(pkt)) };
+    }
+    match result {
+        1.. => Ok(()),
+        0 => Err(SendError::NoSubscriber),
+        // msg_send's only error return
+        _ => Err(SendError::InvalidPID),
+    }
+}
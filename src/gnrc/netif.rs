@@ -3,7 +3,7 @@
 
 // FIXME: Move some of mod.rs in here
 
-use crate::gnrc_pktbuf::{Mode, NotEnoughSpace, Pktsnip, Writable};
+use crate::gnrc_pktbuf::{Mode, NotEnoughSpace, Pktsnip, Shared, Writable};
 use crate::thread::KernelPID;
 use riot_sys::{gnrc_netif_hdr_t, gnrc_nettype_t_GNRC_NETTYPE_NETIF as GNRC_NETTYPE_NETIF};
 
@@ -92,6 +92,65 @@ impl HeaderBuilt {
     }
 }
 
+impl super::Netif {
+    /// Send a packet through the interface.
+    ///
+    /// The packet is expected to start with a netif header (see [Pktsnip::netif_hdr_builder]).
+    /// It is consumed, and thus can not be used any more after it has been handed to GNRC.
+    #[doc(alias = "gnrc_netapi_send")]
+    pub fn send_pkt(
+        &self,
+        pkt: impl Into<Pktsnip<Shared>>,
+    ) -> Result<(), super::netapi::SendError> {
+        super::netapi::send(self.pid(), pkt)
+    }
+
+    /// Send a packet through the interface like [Self::send_pkt], but check its size first
+    ///
+    /// Unless `allow_fragment` is set, a packet whose payload (everything after the netif
+    /// header) exceeds the interface's [MTU](Self::mtu) is not sent, but released with a
+    /// [TooBig](BoundedSendError::TooBig) error. With `allow_fragment`, it is passed on
+    /// regardless, leaving it to the interface to fragment or to drop it.
+    pub fn send_pkt_bounded(
+        &self,
+        pkt: impl Into<Pktsnip<Shared>>,
+        allow_fragment: bool,
+    ) -> Result<(), BoundedSendError> {
+        let pkt: Pktsnip<Shared> = pkt.into();
+        if !allow_fragment {
+            let mtu = self.mtu().map_err(BoundedSendError::NoMtu)?;
+            let pkt_size: usize = pkt
+                .iter_snips()
+                .filter(|snip| snip.type_ != GNRC_NETTYPE_NETIF)
+                .map(|snip| snip.data.len())
+                .sum();
+            if pkt_size > usize::from(mtu) {
+                return Err(BoundedSendError::TooBig { mtu, pkt_size });
+            }
+        }
+        Ok(self.send_pkt(pkt)?)
+    }
+}
+
+/// Error type of [Netif::send_pkt_bounded](super::Netif::send_pkt_bounded)
+///
+/// In all cases, the packet was consumed.
+#[derive(Debug)]
+pub enum BoundedSendError {
+    /// The packet was not taken by the interface (see [super::netapi::send])
+    Send(super::netapi::SendError),
+    /// The interface's MTU could not be determined, so the packet was not sent
+    NoMtu(crate::error::NumericError),
+    /// The packet exceeds what the interface can send in a single frame, so it was not sent
+    TooBig { mtu: u16, pkt_size: usize },
+}
+
+impl From<super::netapi::SendError> for BoundedSendError {
+    fn from(e: super::netapi::SendError) -> Self {
+        BoundedSendError::Send(e)
+    }
+}
+
 impl<M: Mode> Pktsnip<M> {
     /// Get the Netif header of the snip, if there is any thusly typed snip present
     // Note that we can *not* just implement this with &mut on a Writable Pktsnip, because
@@ -0,0 +1,102 @@
+//! Notifications about changes on network interfaces
+//!
+//! GNRC announces events on an interface through the interface's [message
+//! buses](crate::msg::bus). Currently, RIOT only announces IPv6 addresses becoming valid (eg.
+//! when duplicate address detection completed, or when an address was configured from a router
+//! advertisement); link state changes and address removals are not announced.
+//!
+//! ```no_run
+//! # use core::pin::pin;
+//! # use riot_wrappers::gnrc::{Netif, netif_event::{EventSubscription, NetifEvent}};
+//! # let netif: Netif = unimplemented!();
+//! let mut events = pin!(EventSubscription::new(&netif));
+//! events.as_mut().start();
+//! loop {
+//!     match events.as_mut().recv() {
+//!         NetifEvent::AddrValid(addr) => riot_wrappers::println!("New address: {:?}", addr),
+//!         _ => (),
+//!     }
+//! }
+//! ```
+
+use core::pin::Pin;
+
+use pin_project::pin_project;
+
+use crate::gnrc::ipv6::Address;
+use crate::gnrc::Netif;
+use crate::msg::bus::{MsgBus, Subscriber};
+
+/// An event announced on a network interface
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum NetifEvent {
+    /// An address of the interface became valid
+    #[doc(alias = "GNRC_IPV6_EVENT_ADDR_VALID")]
+    AddrValid(Address),
+}
+
+/// Pointer to an address in an interface's address table, as posted on the IPv6 bus
+#[derive(Copy, Clone)]
+struct AddrPtr(*const riot_sys::ipv6_addr_t);
+
+// unsafe: The pointer is only sent along and dereferenced by the receiving thread, pointing into
+// the static interface table.
+unsafe impl Send for AddrPtr {}
+
+/// A subscription to the events of a network interface
+///
+/// Like all [Subscriber]s, it can not move once it is attached to the bus, and is thus used
+/// pinned; it is only attached when [Self::start] is called, and detached when dropped.
+#[pin_project]
+pub struct EventSubscription {
+    #[pin]
+    subscriber: Subscriber<'static, AddrPtr>,
+}
+
+impl EventSubscription {
+    /// Prepare a subscription to the interface's events
+    #[doc(alias = "gnrc_netif_get_bus")]
+    pub fn new(netif: &Netif) -> Self {
+        // unsafe: Network interfaces are statically allocated, and the IPv6 bus only carries
+        // address pointers.
+        let bus: &'static MsgBus<AddrPtr> = unsafe {
+            MsgBus::from_ptr(crate::inline_cast_mut(
+                riot_sys::inline::gnrc_netif_get_bus(
+                    crate::inline_cast_mut(netif.0 as *mut riot_sys::gnrc_netif_t),
+                    riot_sys::gnrc_netif_bus_t_GNRC_NETIF_BUS_IPV6 as _,
+                ),
+            ))
+        };
+        EventSubscription {
+            subscriber: Subscriber::new(bus),
+        }
+    }
+
+    /// Start receiving events in the current thread
+    ///
+    /// Events that occur before this is called are not received.
+    pub fn start(self: Pin<&mut Self>) {
+        self.project()
+            .subscriber
+            .subscribe(riot_sys::gnrc_ipv6_event_t_GNRC_IPV6_EVENT_ADDR_VALID as _);
+    }
+
+    /// Block until an event occurs on the interface
+    ///
+    /// Events are delivered as messages, which are lost if the thread is busy when they are
+    /// sent; the thread should have a message queue set up. Messages that are not events of the
+    /// interface are discarded.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if called from another thread than the one [Self::start] was called in.
+    pub fn recv(self: Pin<&mut Self>) -> NetifEvent {
+        loop {
+            let (event, AddrPtr(addr)) = self.as_mut().project().subscriber.recv();
+            if event as u32 == riot_sys::gnrc_ipv6_event_t_GNRC_IPV6_EVENT_ADDR_VALID as u32 {
+                return NetifEvent::AddrValid(Address::clone_from_ptr(addr));
+            }
+        }
+    }
+}
@@ -8,11 +8,13 @@
 #[cfg(riot_module_gnrc_netapi_callbacks)]
 pub mod callback;
 
-#[cfg(feature = "with_msg_v2")]
+use core::marker::PhantomPinned;
 use core::mem::MaybeUninit;
+use core::pin::Pin;
 
-#[cfg(feature = "with_msg_v2")]
-use crate::error::NegativeErrorExt;
+use pin_project::{pin_project, pinned_drop};
+
+use crate::error::{NegativeErrorExt, NumericError};
 
 // Transmuting the pointer into a Pktsnip does the right thing by treating it as a smart
 // pointer; dropping it decrements the refcount. (Otherwise we'd leak packets).
@@ -96,3 +98,108 @@ impl FullDemuxContext {
         }
     }
 }
+
+/// A place where a netreg entry can be stored while it is registered
+///
+/// GNRC keeps the registered entries in a linked list, so an entry can not move while it is
+/// registered. Registrations that can end (eg. of a UDP socket or ping receiver) thus take
+/// their slot pinned; a simple `pin!(Default::default())` will do to get a suitable slot.
+///
+/// The registration ends when the slot is dropped at the latest; the users of a slot end it
+/// already when they are dropped themselves, so that it does not outlive them.
+///
+/// Besides the entry, the slot holds any data `X` the entry points to; plain entries that deliver
+/// messages to a thread need none.
+///
+/// ## Invariants
+///
+/// If the registration is present, its entry is initialized and registered for its net type.
+#[pin_project(PinnedDrop)]
+pub struct EntrySlot<X = ()>(#[pin] Option<Registration<X>>, PhantomPinned);
+
+struct Registration<X> {
+    nettype: riot_sys::gnrc_nettype_t,
+    entry: MaybeUninit<riot_sys::gnrc_netreg_entry_t>,
+    extra: X,
+}
+
+impl<X> Default for EntrySlot<X> {
+    fn default() -> Self {
+        Self(None, PhantomPinned)
+    }
+}
+
+impl<X> EntrySlot<X> {
+    /// Place a registration in the slot, and register its entry
+    ///
+    /// Any previous registration is ended first. The entry is initialized by `init` when the
+    /// extra data is already in its final place, so the entry may point to it.
+    fn register_with(
+        mut self: Pin<&mut Self>,
+        nettype: riot_sys::gnrc_nettype_t,
+        extra: X,
+        init: impl FnOnce(*mut riot_sys::gnrc_netreg_entry_t, &mut X),
+    ) -> Result<(), NumericError> {
+        self.as_mut().close();
+
+        // unsafe: The registration is placed in its final position before it is registered
+        let placed =
+            unsafe { Pin::into_inner_unchecked(self.as_mut().project().0) }.insert(Registration {
+                nettype,
+                entry: MaybeUninit::uninit(),
+                extra,
+            });
+        init(placed.entry.as_mut_ptr(), &mut placed.extra);
+        if let Err(e) =
+            (unsafe { riot_sys::gnrc_netreg_register(nettype, placed.entry.as_mut_ptr()) })
+                .negative_to_error()
+        {
+            // unsafe: It was not registered, so it can be removed.
+            *unsafe { Pin::into_inner_unchecked(self.project().0) } = None;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// End any registration, leaving the slot empty
+    #[doc(alias = "gnrc_netreg_unregister")]
+    pub(crate) fn close(self: Pin<&mut Self>) {
+        // unsafe: The entry is removed from GNRC's list before it is dropped
+        let registration = unsafe { Pin::into_inner_unchecked(self.project().0) };
+        if let Some(registration) = registration.as_mut() {
+            // unsafe: Initialized and registered as per the invariants
+            unsafe {
+                riot_sys::gnrc_netreg_unregister(
+                    registration.nettype,
+                    registration.entry.as_mut_ptr(),
+                )
+            };
+        }
+        *registration = None;
+    }
+}
+
+impl EntrySlot {
+    /// Register for packets matching the context to be sent as messages to the given thread
+    pub(crate) fn register_pid(
+        self: Pin<&mut Self>,
+        context: FullDemuxContext,
+        pid: crate::thread::KernelPID,
+    ) -> Result<(), NumericError> {
+        // unsafe: C API initializing the entry
+        self.register_with(context.nettype, (), |entry, ()| unsafe {
+            riot_sys::gnrc_netreg_entry_init_pid(
+                crate::inline_cast_mut(entry),
+                context.demux_ctx,
+                pid.into(),
+            )
+        })
+    }
+}
+
+#[pinned_drop]
+impl<X> PinnedDrop for EntrySlot<X> {
+    fn drop(self: Pin<&mut Self>) {
+        self.close();
+    }
+}
@@ -1,9 +1,10 @@
 //! Callback based registration to a the netreg infrastructure
 
 use core::mem::MaybeUninit;
+use core::pin::Pin;
 
-use super::FullDemuxContext;
-use crate::error::NegativeErrorExt;
+use super::{EntrySlot, FullDemuxContext};
+use crate::error::{NegativeErrorExt, NumericError};
 use crate::gnrc_pktbuf as pktbuf;
 
 /// Storage for everything that is needed to serve a registered GNRC netreg [Callback].
@@ -56,23 +57,6 @@ pub fn register_static<C: Callback>(
     callback: C,
     context: FullDemuxContext,
 ) {
-    unsafe extern "C" fn c_callback<C: Callback>(
-        cmd: u16,
-        pkt: *mut riot_sys::gnrc_pktsnip_t,
-        ctx: *mut riot_sys::libc::c_void,
-    ) {
-        // unsafe: Constructed through the opposite cast, and API promises to deliver that value
-        let callback = unsafe { &mut *(ctx as *mut C) };
-        let cmd = match cmd as _ {
-            riot_sys::GNRC_NETAPI_MSG_TYPE_RCV => Command::Receive,
-            riot_sys::GNRC_NETAPI_MSG_TYPE_SND => Command::Send,
-            _ => panic!("gnc_netreg_entry_cb_t precondition failed"),
-        };
-        // unsafe: Trusting the C API to produce a snip along with ownership
-        let pkt = unsafe { pktbuf::Pktsnip::<pktbuf::Shared>::from_ptr(pkt) };
-        callback.called(cmd, pkt)
-    }
-
     slot.2.write(callback);
 
     slot.1.write(riot_sys::gnrc_netreg_entry_cbd_t {
@@ -93,6 +77,80 @@ pub fn register_static<C: Callback>(
     }
 }
 
+/// The C side of any registered [Callback], whose `ctx` points to the callback
+unsafe extern "C" fn c_callback<C: Callback>(
+    cmd: u16,
+    pkt: *mut riot_sys::gnrc_pktsnip_t,
+    ctx: *mut riot_sys::libc::c_void,
+) {
+    // unsafe: Constructed through the opposite cast, and API promises to deliver that value
+    let callback = unsafe { &*(ctx as *const C) };
+    let cmd = match cmd as _ {
+        riot_sys::GNRC_NETAPI_MSG_TYPE_RCV => Command::Receive,
+        riot_sys::GNRC_NETAPI_MSG_TYPE_SND => Command::Send,
+        _ => panic!("gnc_netreg_entry_cb_t precondition failed"),
+    };
+    // unsafe: Trusting the C API to produce a snip along with ownership
+    let pkt = unsafe { pktbuf::Pktsnip::<pktbuf::Shared>::from_ptr(pkt) };
+    callback.called(cmd, pkt)
+}
+
+/// Data a netreg entry in a [CallbackSlot] points to
+///
+/// This is only created when registering through [register].
+pub struct CallbackData<C>(riot_sys::gnrc_netreg_entry_cbd_t, C);
+
+/// A place where a [Callback] can be stored while it is registered
+///
+/// Unlike a [Slot], this can be reused, and need not be static; see [EntrySlot] for details.
+pub type CallbackSlot<C> = EntrySlot<CallbackData<C>>;
+
+/// Set up a `callback` for whenever a packet matching `context` arrives, for as long as the
+/// returned registration is kept.
+///
+/// The callback's [Callback::called] method will be called whenever a packet is sent or received
+/// that matches the given `context`.
+#[doc(alias = "gnrc_netreg_register")]
+pub fn register<'a, C: Callback>(
+    mut slot: Pin<&'a mut CallbackSlot<C>>,
+    callback: C,
+    context: FullDemuxContext,
+) -> Result<Registered<'a, C>, NumericError> {
+    let cbd = riot_sys::gnrc_netreg_entry_cbd_t {
+        cb: Some(c_callback::<C>),
+        // Set once the callback is in place
+        ctx: core::ptr::null_mut(),
+    };
+    slot.as_mut().register_with(
+        context.nettype,
+        CallbackData(cbd, callback),
+        |entry, CallbackData(cbd, callback)| {
+            cbd.ctx = callback as *mut C as *mut riot_sys::libc::c_void;
+            // unsafe: C API initializing the entry, which stays in place with the data it points to
+            unsafe {
+                riot_sys::inline::gnrc_netreg_entry_init_cb(
+                    crate::inline_cast_mut(entry),
+                    context.demux_ctx,
+                    crate::inline_cast_mut(cbd as *mut riot_sys::gnrc_netreg_entry_cbd_t),
+                )
+            };
+        },
+    )?;
+    Ok(Registered(slot))
+}
+
+/// A [Callback] registered through [register]
+///
+/// The registration ends when this is dropped. GNRC does not call callbacks while their
+/// registrations are being removed, so the callback is not in use any more after that.
+pub struct Registered<'a, C>(Pin<&'a mut CallbackSlot<C>>);
+
+impl<C> Drop for Registered<'_, C> {
+    fn drop(&mut self) {
+        self.0.as_mut().close();
+    }
+}
+
 /// Values of the command argument of a netreg callback
 #[non_exhaustive]
 #[derive(Debug, PartialEq, Eq)]
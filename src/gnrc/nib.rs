@@ -181,3 +181,70 @@ fn any_nc_query(interface: riot_sys::libc::c_uint) -> impl Iterator<Item = NcEnt
         state: core::ptr::null_mut(),
     }
 }
+
+/// A single entry in the default router list.
+///
+/// These can be obtained by iterating over [DefaultRouter::all()].
+pub struct DefaultRouter(riot_sys::gnrc_ipv6_nib_dr_t);
+
+impl DefaultRouter {
+    /// Iterate over the default router list.
+    #[doc(alias = "gnrc_ipv6_nib_dr_iter")]
+    pub fn all() -> DefaultRouterIter {
+        DefaultRouterIter {
+            state: core::ptr::null_mut(),
+        }
+    }
+
+    /// The router's (typically link-local) address
+    pub fn ipv6_addr(&self) -> &crate::gnrc::ipv6::Address {
+        // unsafe: It's repr(transparent) around it
+        unsafe { core::mem::transmute(&self.0.ipv6) }
+    }
+
+    /// The interface through which the router is reachable
+    pub fn iface(&self) -> Option<core::num::NonZero<usize>> {
+        (self.0.iface as usize).try_into().ok()
+    }
+
+    /// Remaining lifetime of the entry in seconds
+    ///
+    /// The maximum value indicates that the entry does not expire.
+    pub fn lifetime_sec(&self) -> u16 {
+        self.0.lifetime
+    }
+}
+
+impl core::fmt::Debug for DefaultRouter {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("DefaultRouter")
+            .field("ipv6_addr", self.ipv6_addr())
+            .field("iface", &self.iface())
+            .field("lifetime_sec", &self.lifetime_sec())
+            .finish()
+    }
+}
+
+/// Iterator over the default router list, see [DefaultRouter::all()]
+///
+/// The list is not locked while iterating; entries that are removed concurrently may or may not
+/// be reported.
+pub struct DefaultRouterIter {
+    state: *mut riot_sys::libc::c_void,
+}
+
+impl Iterator for DefaultRouterIter {
+    type Item = DefaultRouter;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut entry = core::mem::MaybeUninit::<riot_sys::gnrc_ipv6_nib_dr_t>::uninit();
+        // unsafe: C API; 0 means "any interface", and the state is only ever produced by the
+        // function itself.
+        if unsafe { riot_sys::gnrc_ipv6_nib_dr_iter(0, &mut self.state, entry.as_mut_ptr()) } {
+            // unsafe: Initialized by the C function when it returns true
+            Some(DefaultRouter(unsafe { entry.assume_init() }))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Dumping GNRC packets to stdout for debugging
+//!
+//! This is a lightweight Rust counterpart to RIOT's `gnrc_pktdump` module: Rather than running a
+//! thread that packets can be dispatched to, it prints packets on demand from wherever they are
+//! processed. Only the snips' types and raw data are shown; no headers are parsed.
+
+use core::fmt::Write;
+
+use crate::gnrc_pktbuf::{Mode, Pktsnip};
+use crate::stdio::Stdio;
+
+/// Write a hex dump of each snip of the packet to stdout.
+///
+/// Snips are shown in list order, ie. in reception order for received packets (where the
+/// payload comes first), and innermost last for packets under construction.
+pub fn dump_pkt<M: Mode>(pkt: &Pktsnip<M>) {
+    let _ = write_pkt(&mut Stdio {}, pkt);
+}
+
+/// Write a hex dump of each snip of the packet to any writer (see [dump_pkt] for details).
+pub fn write_pkt<M: Mode>(w: &mut impl Write, pkt: &Pktsnip<M>) -> core::fmt::Result {
+    writeln!(w, "Packet of {} bytes in {} snips", pkt.len(), pkt.count())?;
+    for (i, snip) in pkt.iter_snips().enumerate() {
+        writeln!(
+            w,
+            "~~ Snip {}: {:?}, {} bytes",
+            i,
+            crate::gnrc::NetType(snip.type_),
+            snip.data.len()
+        )?;
+        for (line, chunk) in snip.data.chunks(16).enumerate() {
+            write!(w, "{:08x}", line * 16)?;
+            for byte in chunk {
+                write!(w, " {:02x}", byte)?;
+            }
+            writeln!(w)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,191 @@
+//! Control of the [RPL routing protocol](https://doc.riot-os.org/group__net__gnrc__rpl.html)
+//!
+//! RPL builds a routing tree (a DODAG) in a mesh network. One node is set up as the root through
+//! [root()]; the other nodes only need RPL to be [init()]ialized on their interface, and then join
+//! the DODAG when they hear of it. The instances a node participates in can be inspected through
+//! [Instance::all()], and their DODAGs (including the parents the node selected) through [Dodag].
+
+use crate::gnrc::ipv6::Address;
+use crate::gnrc::Netif;
+use crate::thread::KernelPID;
+
+/// Start RPL on an interface, and return the PID of the RPL thread
+///
+/// Returns None if the RPL thread could not be started.
+#[doc(alias = "gnrc_rpl_init")]
+pub fn init(iface: &Netif) -> Option<KernelPID> {
+    // unsafe: C API with a valid interface PID
+    KernelPID::new(unsafe { riot_sys::gnrc_rpl_init(iface.pid().into()) })
+}
+
+/// Create an RPL instance with this node as the root of its DODAG
+///
+/// The DODAG ID is typically a global address of this node. RPL needs to be [init()]ialized
+/// before. Returns None if the instance could not be created (eg. because all instance slots are
+/// in use, or the DODAG ID is not an address of this node).
+#[doc(alias = "gnrc_rpl_root_init")]
+pub fn root(instance_id: u8, dodag_id: &Address) -> Option<Instance> {
+    // unsafe: C API with a valid address pointer; the result points into the static instance
+    // array and is copied out right away.
+    let instance =
+        unsafe { riot_sys::gnrc_rpl_root_init(instance_id, dodag_id.as_ptr(), false, false) };
+    if instance.is_null() {
+        return None;
+    }
+    // unsafe: Valid pointer by the C API
+    Some(Instance(unsafe { core::ptr::read(instance) }))
+}
+
+/// A snapshot of an RPL instance this node participates in
+///
+/// The instance is copied out of RIOT's instance list; as the RPL thread keeps updating the
+/// instance, later changes are not reflected here.
+pub struct Instance(riot_sys::gnrc_rpl_instance_t);
+
+impl Instance {
+    /// Iterate over the RPL instances that are in use
+    ///
+    /// The instances are not locked while they are copied out; a snapshot taken while the RPL
+    /// thread modifies the instance may be inconsistent.
+    pub fn all() -> impl Iterator<Item = Self> {
+        // unsafe: Only taking the address of the extern static (which needs an unsafe block before
+        // Rust 1.82)
+        #[allow(unused_unsafe)]
+        let instances = unsafe { core::ptr::addr_of!(riot_sys::gnrc_rpl_instances) }
+            as *const riot_sys::gnrc_rpl_instance_t;
+        (0..riot_sys::GNRC_RPL_INSTANCES_NUMOF as usize)
+            // unsafe: In range of the static array; copied out with a volatile read as the RPL
+            // thread may write to it concurrently.
+            .map(move |i| Instance(unsafe { core::ptr::read_volatile(instances.add(i)) }))
+            .filter(|instance| instance.0.state != 0)
+    }
+
+    /// The RPL instance ID
+    pub fn id(&self) -> u8 {
+        self.0.id
+    }
+
+    /// The DODAG ID, ie. an address of the DODAG's root
+    pub fn dodag_id(&self) -> Address {
+        Address::clone_from_ptr(&self.0.dodag.dodag_id)
+    }
+
+    /// The node's rank in the DODAG (lower is closer to the root)
+    pub fn rank(&self) -> u16 {
+        self.0.dodag.my_rank
+    }
+
+    /// The DODAG version number
+    pub fn version(&self) -> u8 {
+        self.0.dodag.version
+    }
+
+    /// The interface on which the node participates in the DODAG
+    pub fn iface(&self) -> Option<Netif> {
+        KernelPID::new(self.0.dodag.iface).and_then(Netif::by_pid)
+    }
+}
+
+impl core::fmt::Debug for Instance {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Instance")
+            .field("id", &self.id())
+            .field("dodag_id", &self.dodag_id())
+            .field("rank", &self.rank())
+            .field("version", &self.version())
+            .finish()
+    }
+}
+
+/// A snapshot of the DODAG of an RPL instance, including the node's parent set
+///
+/// As with [Instance], this is copied out of RIOT's data structures and does not reflect later
+/// changes.
+pub struct Dodag(Instance);
+
+impl Dodag {
+    /// Find the DODAG with the given ID in the RPL instance with the given ID
+    #[doc(alias = "gnrc_rpl_instance_get")]
+    pub fn find(instance_id: u8, dodag_id: &Address) -> Option<Self> {
+        // unsafe: C API; the result points into the static instance array and is copied out right
+        // away.
+        let instance = unsafe { riot_sys::gnrc_rpl_instance_get(instance_id) };
+        if instance.is_null() {
+            return None;
+        }
+        // unsafe: Valid pointer by the C API
+        let instance = Instance(unsafe { core::ptr::read_volatile(instance) });
+        (instance.dodag_id().raw() == dodag_id.raw()).then_some(Dodag(instance))
+    }
+
+    /// The DODAG of which this node is the root, if any
+    pub fn root() -> Option<Self> {
+        Instance::all()
+            .find(|instance| instance.0.dodag.node_status == riot_sys::GNRC_RPL_ROOT_NODE as u8)
+            .map(Dodag)
+    }
+
+    /// The ID of the RPL instance the DODAG belongs to
+    pub fn instance_id(&self) -> u8 {
+        self.0.id()
+    }
+
+    /// The DODAG ID, ie. an address of the DODAG's root
+    pub fn root_addr(&self) -> Address {
+        self.0.dodag_id()
+    }
+
+    /// The node's rank in the DODAG (lower is closer to the root)
+    pub fn rank(&self) -> u16 {
+        self.0.rank()
+    }
+
+    /// Iterate over the parents the node selected in the DODAG, starting with the preferred
+    /// parent
+    ///
+    /// The parents are read from RIOT's parent list as the iterator advances, and are subject to
+    /// the same caveats as [Instance::all()].
+    pub fn parents(&self) -> impl Iterator<Item = Parent> {
+        let mut next = self.0 .0.dodag.parents as *const riot_sys::gnrc_rpl_parent_t;
+        // Bounded to not run in circles when the list is modified while being read
+        (0..riot_sys::GNRC_RPL_PARENTS_NUMOF).map_while(move |_| {
+            if next.is_null() {
+                return None;
+            }
+            // unsafe: Parents are entries of the static parent array; copied out with a volatile
+            // read as the RPL thread may write to it concurrently.
+            let parent = unsafe { core::ptr::read_volatile(next) };
+            next = parent.next;
+            Some(Parent {
+                addr: Address::clone_from_ptr(&parent.addr),
+                rank: parent.rank,
+                link_metric: parent.link_metric,
+            })
+        })
+    }
+}
+
+impl From<Instance> for Dodag {
+    fn from(instance: Instance) -> Self {
+        Dodag(instance)
+    }
+}
+
+impl core::fmt::Debug for Dodag {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Dodag")
+            .field("instance_id", &self.instance_id())
+            .field("root_addr", &self.root_addr())
+            .field("rank", &self.rank())
+            .finish()
+    }
+}
+
+/// A parent in an RPL DODAG, as produced by [Dodag::parents()]
+#[derive(Debug)]
+pub struct Parent {
+    pub addr: Address,
+    pub rank: u16,
+    /// The link metric in the units of the DODAG's objective function
+    pub link_metric: f64,
+}
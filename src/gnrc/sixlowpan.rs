@@ -0,0 +1,4 @@
+//! Components for 6LoWPAN adaptation on GNRC
+
+#[cfg(all(riot_module_gnrc_sixlowpan_ctx, riot_module_ipv6))]
+pub mod ctx;
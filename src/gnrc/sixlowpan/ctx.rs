@@ -0,0 +1,81 @@
+//! Access to the [6LoWPAN compression context buffer](https://doc.riot-os.org/group__net__gnrc__sixlowpan__ctx.html)
+//!
+//! Contexts map a 4-bit context ID to an IPv6 prefix, which header compression can then elide
+//! from addresses. They are typically distributed by a border router through router
+//! advertisements, but can also be managed manually.
+
+use crate::gnrc::ipv6::Address;
+
+/// Number of context IDs available in 6LoWPAN header compression
+pub const ID_NUMOF: u8 = 16;
+
+/// A snapshot of an entry in the context buffer
+///
+/// Entries are copied out of the buffer, so later changes to the buffer (including the expiry of
+/// the context) are not reflected here.
+#[derive(Debug, Copy, Clone)]
+pub struct SixlowpanCtx {
+    /// Context ID, in the range 0 to 15
+    pub id: u8,
+    /// The prefix that is compressed using this context
+    pub prefix: Address,
+    /// Length of the prefix in bits
+    pub prefix_len: u8,
+    /// Whether the context may be used for compression (and not only for decompression)
+    pub compression: bool,
+    /// Lifetime of the context in minutes, as it was set
+    pub lifetime_min: u16,
+}
+
+impl SixlowpanCtx {
+    fn from_ptr(ctx: *const riot_sys::gnrc_sixlowpan_ctx_t) -> Option<Self> {
+        // unsafe: Pointers produced by the C API point into the static context buffer
+        let ctx = unsafe { ctx.as_ref() }?;
+        Some(SixlowpanCtx {
+            id: ctx.flags_id & riot_sys::GNRC_SIXLOWPAN_CTX_FLAGS_CID_MASK as u8,
+            prefix: Address::clone_from_ptr(&ctx.prefix),
+            prefix_len: ctx.prefix_len,
+            compression: ctx.flags_id & riot_sys::GNRC_SIXLOWPAN_CTX_FLAGS_COMP as u8 != 0,
+            lifetime_min: ctx.ltime,
+        })
+    }
+
+    /// Look up the context with a given ID
+    ///
+    /// This returns None if the ID is not in use (or out of range).
+    #[doc(alias = "gnrc_sixlowpan_ctx_lookup_id")]
+    pub fn get(id: u8) -> Option<Self> {
+        if id >= ID_NUMOF {
+            return None;
+        }
+        // unsafe: C API; the result is copied out right away
+        Self::from_ptr(unsafe { riot_sys::gnrc_sixlowpan_ctx_lookup_id(id) })
+    }
+
+    /// Look up the context that matches an address best (ie. with the longest prefix)
+    #[doc(alias = "gnrc_sixlowpan_ctx_lookup_addr")]
+    pub fn lookup(addr: &Address) -> Option<Self> {
+        // unsafe: C API; the result is copied out right away
+        Self::from_ptr(unsafe { riot_sys::gnrc_sixlowpan_ctx_lookup_addr(addr.as_ptr()) })
+    }
+
+    /// Create or replace the context with the given ID
+    ///
+    /// New contexts are usable for compression. A lifetime of 0 invalidates the context. This
+    /// returns None if the ID or prefix length is out of range.
+    #[doc(alias = "gnrc_sixlowpan_ctx_update")]
+    pub fn update(id: u8, prefix: &Address, prefix_len: u8, lifetime_min: u16) -> Option<Self> {
+        if id >= ID_NUMOF || prefix_len == 0 || prefix_len > 128 {
+            return None;
+        }
+        // unsafe: C API with a valid address pointer; the result is copied out right away
+        Self::from_ptr(unsafe {
+            riot_sys::gnrc_sixlowpan_ctx_update(id, prefix.as_ptr(), prefix_len, lifetime_min, true)
+        })
+    }
+
+    /// Iterate over all contexts that are in use
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..ID_NUMOF).filter_map(Self::get)
+    }
+}
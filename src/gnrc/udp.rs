@@ -0,0 +1,271 @@
+//! A minimal UDP socket built directly on GNRC registrations and packet buffers
+//!
+//! This does not go through the sock API, but registers with [netreg](super::netreg) for incoming
+//! packets and dispatches outgoing ones to GNRC's UDP layer. Received packets are delivered as
+//! messages to the thread that bound the socket.
+
+use core::num::NonZeroU16;
+use core::pin::Pin;
+
+use riot_sys::gnrc_nettype_t_GNRC_NETTYPE_UDP as GNRC_NETTYPE_UDP;
+use riot_sys::gnrc_nettype_t_GNRC_NETTYPE_UNDEF as GNRC_NETTYPE_UNDEF;
+
+use super::netreg::FullDemuxContext;
+use crate::error::NumericError;
+use crate::gnrc::ipv6::Address;
+use crate::gnrc::Netif;
+use crate::gnrc_pktbuf::{NotEnoughSpace, Pktsnip, Shared, Writable};
+use crate::msg::{Msg, OpaqueMsg, WrapsMsgT};
+use crate::thread::KernelPID;
+
+/// Error type of [UdpSocket] operations
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UdpError {
+    /// The packet buffer had no space for the outgoing packet
+    NotEnoughSpace,
+    /// The destination port 0 can not be sent to
+    InvalidPort,
+    /// No GNRC component was registered to take the packet
+    NotDispatched,
+}
+
+impl From<NotEnoughSpace> for UdpError {
+    fn from(_: NotEnoughSpace) -> Self {
+        UdpError::NotEnoughSpace
+    }
+}
+
+impl From<UdpError> for NumericError {
+    fn from(e: UdpError) -> NumericError {
+        match e {
+            UdpError::NotEnoughSpace => crate::error::ENOMEM,
+            UdpError::InvalidPort => crate::error::EINVAL,
+            UdpError::NotDispatched => NumericError::from_constant(riot_sys::EHOSTUNREACH as _),
+        }
+    }
+}
+
+/// A place where a [UdpSocket]'s netreg entry can be stored
+pub type UdpSocketSlot = super::netreg::EntrySlot;
+
+/// A UDP socket bound to a local port
+///
+/// Packets are received through GNRC messages to the thread that created the socket. For
+/// packets not to get lost while that thread is busy, it should have a message queue set up.
+///
+/// ## Invariants
+///
+/// While this is active, the slot holds its registration.
+pub struct UdpSocket<'a> {
+    slot: Pin<&'a mut UdpSocketSlot>,
+    port: NonZeroU16,
+    pid: KernelPID,
+}
+
+impl<'a> UdpSocket<'a> {
+    /// Register for UDP packets on the given port for the current thread.
+    ///
+    /// The port may not be zero (for the registration would then catch packets sent to that
+    /// port, rather than using an ephemeral port).
+    #[doc(alias = "gnrc_netreg_register")]
+    pub fn bind(port: u16, mut slot: Pin<&'a mut UdpSocketSlot>) -> Result<Self, NumericError> {
+        let port = NonZeroU16::new(port).ok_or(crate::error::EINVAL)?;
+        let pid = crate::thread::get_pid();
+
+        slot.as_mut().register_pid(
+            FullDemuxContext::new_raw(GNRC_NETTYPE_UDP, port.get().into()),
+            pid,
+        )?;
+
+        Ok(UdpSocket { slot, port, pid })
+    }
+
+    /// The local port the socket is bound to
+    pub fn port(&self) -> u16 {
+        self.port.get()
+    }
+
+    /// Send a UDP datagram from the bound port to the given destination.
+    ///
+    /// The UDP and IPv6 headers are built here; the UDP checksum is filled in by GNRC (through
+    /// `gnrc_udp_calc_csum`) once the IPv6 layer has completed the headers it depends on.
+    #[doc(alias = "gnrc_udp_hdr_build")]
+    pub fn send_to(&self, dst: &Address, dst_port: u16, data: &[u8]) -> Result<(), UdpError> {
+        self.send_on(dst, dst_port, data, None)
+    }
+
+    /// Like [Self::send_to], but optionally pinning the packet to an outgoing interface
+    fn send_on(
+        &self,
+        dst: &Address,
+        dst_port: u16,
+        data: &[u8],
+        iface: Option<&Netif>,
+    ) -> Result<(), UdpError> {
+        let dst_port = NonZeroU16::new(dst_port).ok_or(UdpError::InvalidPort)?;
+
+        let payload = Pktsnip::<Writable>::allocate_from(data, GNRC_NETTYPE_UNDEF)?;
+        let pkt = payload
+            .udp_hdr_build(self.port, dst_port)?
+            .ipv6_hdr_build(None, Some(dst))?;
+        let pkt = match iface {
+            Some(iface) => pkt
+                .netif_hdr_builder()
+                .without_link_layer_addresses()
+                .with_if_pid(iface.pid())
+                .finish()?,
+            None => pkt,
+        };
+
+        match super::netapi::dispatch_send(
+            GNRC_NETTYPE_UDP,
+            riot_sys::GNRC_NETREG_DEMUX_CTX_ALL,
+            pkt,
+        ) {
+            0 => Err(UdpError::NotDispatched),
+            _ => Ok(()),
+        }
+    }
+
+    /// Block until a UDP datagram arrives, copy its payload into the buffer, and return the
+    /// copied length along with the sender's address and port.
+    ///
+    /// Payload exceeding the buffer is discarded. Messages that are not received packets are
+    /// discarded as well, so this is best used in threads dedicated to the socket.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if called from another thread than the one that bound the socket.
+    pub fn recv(&mut self, buf: &mut [u8]) -> Result<(usize, Address, u16), UdpError> {
+        assert!(
+            crate::thread::get_pid() == self.pid,
+            "UDP socket used outside the thread it was bound in"
+        );
+
+        loop {
+            let msg = OpaqueMsg::receive();
+            if msg.get_type() != riot_sys::GNRC_NETAPI_MSG_TYPE_RCV as u16 {
+                continue;
+            }
+            // unsafe: Messages of that type carry a packet along with its ownership
+            let pkt = unsafe { Pktsnip::<Shared>::from_ptr(msg.view().content.ptr as *mut _) };
+
+            let Some(src) = pkt.ipv6_get_header().map(|ip| *ip.src()) else {
+                continue;
+            };
+            let Some(src_port) = udp_src_port(&pkt) else {
+                continue;
+            };
+
+            // In received packets, the first snip carries the payload
+            let payload = pkt.data();
+            let len = payload.len().min(buf.len());
+            buf[..len].copy_from_slice(&payload[..len]);
+            return Ok((len, src, src_port));
+        }
+    }
+}
+
+impl Drop for UdpSocket<'_> {
+    fn drop(&mut self) {
+        // Frees the port already now rather than when the slot goes
+        self.slot.as_mut().close();
+    }
+}
+
+/// A [UdpSocket] that is additionally subscribed to an IPv6 multicast group on an interface
+///
+/// The group is left again when this is dropped. As interfaces do not count their group
+/// memberships, this also ends any other membership in the same group on the interface.
+pub struct MulticastSocket<'a> {
+    socket: UdpSocket<'a>,
+    iface: Netif,
+    group: Address,
+}
+
+impl<'a> MulticastSocket<'a> {
+    /// Join the group on the interface, and bind a socket to the given port.
+    ///
+    /// The socket receives packets sent to the port on any address (including, but not limited
+    /// to, the group).
+    pub fn join(
+        iface: &Netif,
+        group: &Address,
+        port: u16,
+        slot: Pin<&'a mut UdpSocketSlot>,
+    ) -> Result<Self, NumericError> {
+        let socket = UdpSocket::bind(port, slot)?;
+        iface.join_ipv6_group(group)?;
+        Ok(MulticastSocket {
+            socket,
+            iface: Netif(iface.0),
+            group: *group,
+        })
+    }
+
+    /// Leave the group and release the port
+    ///
+    /// This is equivalent to dropping the socket, but reports errors in leaving the group.
+    #[doc(alias = "gnrc_netif_ipv6_group_leave")]
+    pub fn leave(self) -> Result<(), NumericError> {
+        let this = core::mem::ManuallyDrop::new(self);
+        let result = this.iface.leave_ipv6_group(&this.group);
+        // unsafe: Taken out exactly once, and `this` is not dropped
+        drop(unsafe { core::ptr::read(&this.socket) });
+        result
+    }
+
+    /// The group the socket joined
+    pub fn group(&self) -> &Address {
+        &self.group
+    }
+
+    /// Send a UDP datagram to a multicast group through the socket's interface
+    ///
+    /// This does not need to be the joined group; for example, a CoAP server in `ff02::fd` may
+    /// send discovery requests to `ff05::fd`. Sending to an address that is not a multicast
+    /// address fails with `EINVAL`, as do the [errors of sending](UdpError) on the socket.
+    pub fn send_multicast(
+        &self,
+        group: &Address,
+        port: u16,
+        data: &[u8],
+    ) -> Result<(), NumericError> {
+        if !group.is_multicast() {
+            return Err(crate::error::EINVAL);
+        }
+        Ok(self.socket.send_on(group, port, data, Some(&self.iface))?)
+    }
+}
+
+impl<'a> core::ops::Deref for MulticastSocket<'a> {
+    type Target = UdpSocket<'a>;
+
+    fn deref(&self) -> &UdpSocket<'a> {
+        &self.socket
+    }
+}
+
+impl<'a> core::ops::DerefMut for MulticastSocket<'a> {
+    fn deref_mut(&mut self) -> &mut UdpSocket<'a> {
+        &mut self.socket
+    }
+}
+
+impl Drop for MulticastSocket<'_> {
+    fn drop(&mut self) {
+        // Errors can not be reported here; leave() is available to see them.
+        let _ = self.iface.leave_ipv6_group(&self.group);
+    }
+}
+
+fn udp_src_port(pkt: &Pktsnip<Shared>) -> Option<u16> {
+    let snip = pkt.search_type(GNRC_NETTYPE_UDP)?;
+    if snip.data.len() < core::mem::size_of::<riot_sys::udp_hdr_t>() {
+        return None;
+    }
+    // unsafe: Length checked above; the header type has alignment 1.
+    let hdr: &riot_sys::udp_hdr_t = unsafe { &*(snip.data.as_ptr() as *const _) };
+    Some(u16::from_be_bytes(unsafe { hdr.src_port.u8_ }))
+}
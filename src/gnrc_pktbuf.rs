@@ -261,7 +261,6 @@ impl<'a> Pktsnip<Writable> {
         nettype: gnrc_nettype_t,
     ) -> Result<Self, NotEnoughSpace> {
         let next_ptr = next.as_ref().map(|s| s.ptr).unwrap_or(0 as *mut _);
-        forget(next);
         let snip = unsafe {
             gnrc_pktbuf_add(
                 next_ptr,
@@ -271,8 +270,11 @@ impl<'a> Pktsnip<Writable> {
             )
         };
         if snip == 0 as *mut _ {
+            // next is still ours, and is released when dropped here
             return Err(NotEnoughSpace);
         }
+        // On success, next is now referenced by the new snip
+        forget(next);
         Ok(unsafe { Pktsnip::<Writable>::from_ptr(snip) })
     }
 
@@ -295,6 +297,97 @@ impl<'a> Pktsnip<Writable> {
     }
 }
 
+/// Builder for packets that are assembled from the payload outwards
+///
+/// Snips are added in the order of decreasing depth: The first snip added is the payload, and
+/// each subsequent snip (typically a header) is placed in front of it, as GNRC expects it. Errors
+/// are carried through the chain, and with them, any snips allocated so far are released.
+///
+/// ```ignore
+/// let pkt = PktBuilder::new(b"Hello", GNRC_NETTYPE_UNDEF)
+///     .add_snip(&custom_header, GNRC_NETTYPE_CUSTOM)
+///     .build()?;
+/// ```
+pub struct PktBuilder(Result<Pktsnip<Writable>, NotEnoughSpace>);
+
+impl PktBuilder {
+    /// Start building a packet from its payload
+    pub fn new(payload: &[u8], nettype: gnrc_nettype_t) -> Self {
+        PktBuilder(Pktsnip::<Writable>::allocate_from(payload, nettype))
+    }
+
+    /// Place a snip with a copy of the data in front of what was built so far
+    #[doc(alias = "gnrc_pktbuf_add")]
+    pub fn add_snip(self, data: &[u8], nettype: gnrc_nettype_t) -> Self {
+        self.map(|next| Pktsnip::<Writable>::_add(Some(next), data.as_ptr(), data.len(), nettype))
+    }
+
+    /// Build a UDP header in front of what was built so far
+    #[cfg(riot_module_udp)]
+    #[doc(alias = "gnrc_udp_hdr_build")]
+    pub fn udp(self, src: core::num::NonZeroU16, dst: core::num::NonZeroU16) -> Self {
+        self.map(|next| next.udp_hdr_build(src, dst))
+    }
+
+    /// Build an IPv6 header in front of what was built so far
+    #[cfg(riot_module_ipv6)]
+    #[doc(alias = "gnrc_ipv6_hdr_build")]
+    pub fn ipv6(
+        self,
+        src: Option<&crate::gnrc::ipv6::Address>,
+        dst: Option<&crate::gnrc::ipv6::Address>,
+    ) -> Self {
+        self.map(|next| next.ipv6_hdr_build(src, dst))
+    }
+
+    /// Build a netif header without link layer addresses in front of what was built so far
+    #[doc(alias = "gnrc_netif_hdr_build")]
+    pub fn netif(self) -> Self {
+        self.map(|next| {
+            next.netif_hdr_builder()
+                .without_link_layer_addresses()
+                .finish()
+        })
+    }
+
+    /// Build a complete UDP datagram in IPv6 with a netif header
+    ///
+    /// The UDP checksum is not calculated here; GNRC does that when the packet is sent through
+    /// its IPv6 layer.
+    #[cfg(all(riot_module_udp, riot_module_ipv6))]
+    pub fn udp_ipv6(
+        src: &crate::gnrc::ipv6::Address,
+        dst: &crate::gnrc::ipv6::Address,
+        src_port: core::num::NonZeroU16,
+        dst_port: core::num::NonZeroU16,
+        payload: &[u8],
+    ) -> Self {
+        Self::new(payload, riot_sys::gnrc_nettype_t_GNRC_NETTYPE_UNDEF)
+            .udp(src_port, dst_port)
+            .ipv6(Some(src), Some(dst))
+            .netif()
+    }
+
+    /// Apply a building step to the packet built so far, if no previous step failed
+    pub(crate) fn map(
+        self,
+        step: impl FnOnce(Pktsnip<Writable>) -> Result<Pktsnip<Writable>, NotEnoughSpace>,
+    ) -> Self {
+        PktBuilder(self.0.and_then(step))
+    }
+
+    /// Finish building, producing the packet or an error if any of the steps failed
+    pub fn build(self) -> Result<Pktsnip<Writable>, NotEnoughSpace> {
+        self.0
+    }
+}
+
+impl ::core::fmt::Debug for PktBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        f.debug_tuple("PktBuilder").field(&self.0).finish()
+    }
+}
+
 impl<M: Mode> ::core::fmt::Debug for Pktsnip<M> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         let mode = core::any::type_name::<M>();
@@ -17,6 +17,22 @@ impl InputPin for InputGPIO {
     }
 }
 
+#[cfg(riot_module_periph_gpio_irq)]
+impl ErrorType for IrqGPIO {
+    type Error = Infallible;
+}
+
+#[cfg(riot_module_periph_gpio_irq)]
+impl InputPin for IrqGPIO {
+    fn is_high(&mut self) -> Result<bool, Infallible> {
+        Ok(IrqGPIO::is_high(self))
+    }
+
+    fn is_low(&mut self) -> Result<bool, Infallible> {
+        Ok(IrqGPIO::is_low(self))
+    }
+}
+
 impl ErrorType for OutputGPIO {
     type Error = Infallible;
 }
@@ -0,0 +1,203 @@
+use super::*;
+
+/// Edge of a signal on which an [IrqGPIO] triggers its callback
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub enum Flank {
+    Rising,
+    Falling,
+    Both,
+}
+
+impl Flank {
+    fn to_c(self) -> riot_sys::gpio_flank_t {
+        match self {
+            Flank::Rising => riot_sys::gpio_flank_t_GPIO_RISING,
+            Flank::Falling => riot_sys::gpio_flank_t_GPIO_FALLING,
+            Flank::Both => riot_sys::gpio_flank_t_GPIO_BOTH,
+        }
+    }
+}
+
+/// Number of pins that can be configured for interrupts through an [IrqGPIO] at the same time
+const MAX_IRQ_PINS: usize = 16;
+
+/// Pins that are currently configured through an [IrqGPIO]
+///
+/// This ensures that only one exists per pin, as dropping one would otherwise disable the
+/// interrupt under the other. As `gpio_t` values are not numbered contiguously on all platforms,
+/// this is a small table of taken pins rather than a flag per pin.
+struct Taken(core::cell::UnsafeCell<[Option<gpio_t>; MAX_IRQ_PINS]>);
+
+// unsafe: Only accessed with interrupts disabled
+unsafe impl Sync for Taken {}
+
+static TAKEN: Taken = Taken(core::cell::UnsafeCell::new([None; MAX_IRQ_PINS]));
+
+impl Taken {
+    fn take(&self, pin: gpio_t) -> Result<(), crate::error::NumericError> {
+        crate::interrupt::free(|_| {
+            // unsafe: Exclusive access as interrupts are disabled
+            let taken = unsafe { &mut *self.0.get() };
+            if taken.contains(&Some(pin)) {
+                return Err(crate::error::NumericError::from_constant(
+                    riot_sys::EBUSY as _,
+                ));
+            }
+            let slot = taken
+                .iter_mut()
+                .find(|slot| slot.is_none())
+                .ok_or(crate::error::ENOSPC)?;
+            *slot = Some(pin);
+            Ok(())
+        })
+    }
+
+    fn release(&self, pin: gpio_t) {
+        crate::interrupt::free(|_| {
+            // unsafe: Exclusive access as interrupts are disabled
+            let taken = unsafe { &mut *self.0.get() };
+            for slot in taken.iter_mut().filter(|slot| **slot == Some(pin)) {
+                *slot = None;
+            }
+        })
+    }
+}
+
+impl GPIO {
+    /// Configure the pin as an input that calls a function whenever the signal changes on the
+    /// given flank
+    ///
+    /// The callback runs in interrupt context, so it needs to be short, and can only communicate
+    /// with the rest of the program through interrupt safe means (eg. atomics, or sending a
+    /// message to a thread). As C only passes a single pointer along, this takes a plain function
+    /// rather than a closure; any state it works on needs to be in statics, which
+    /// [`static_handler!`](crate::static_handler!) helps setting up.
+    ///
+    /// The interrupt is enabled when this returns. This fails with `EBUSY` if an [IrqGPIO] for
+    /// the same pin exists already, and with `ENOSPC` if 16 pins are configured for interrupts
+    /// already.
+    #[doc(alias = "gpio_init_int")]
+    pub fn configure_as_irq_input(
+        self,
+        mode: InputMode,
+        flank: Flank,
+        callback: fn(),
+    ) -> Result<IrqGPIO, crate::error::NumericError> {
+        extern "C" fn trampoline(arg: *mut riot_sys::libc::c_void) {
+            // unsafe: The argument was produced from a fn() in configure_as_irq_input
+            let callback: fn() = unsafe { core::mem::transmute(arg) };
+            callback();
+        }
+
+        TAKEN.take(self.0)?;
+
+        // unsafe: C API. The function and argument are 'static, so reconfiguring the pin (even
+        // through a different GPIO for the same pin) can not leave a dangling callback behind.
+        unsafe {
+            riot_sys::gpio_init_int(
+                self.0,
+                mode.to_c(),
+                flank.to_c(),
+                Some(trampoline),
+                callback as *mut _,
+            )
+        }
+        .negative_to_error()
+        .inspect_err(|_| TAKEN.release(self.0))?;
+        Ok(IrqGPIO(self))
+    }
+}
+
+/// Define a function usable as a callback of [GPIO::configure_as_irq_input], along with a static
+/// for the state it works on
+///
+/// The static is accessible to the rest of the program under the given name, and to the function
+/// under the name given in its argument position. As the function runs in interrupt context, the
+/// static's type needs to be usable from there (eg. an atomic).
+///
+/// ```no_run
+/// # use riot_wrappers::gpio::{GPIO, Flank, InputMode};
+/// use core::sync::atomic::{AtomicU32, Ordering};
+/// riot_wrappers::static_handler!(
+///     static PRESSES: AtomicU32 = AtomicU32::new(0);
+///     fn count_press(presses) {
+///         presses.fetch_add(1, Ordering::Relaxed);
+///     }
+/// );
+///
+/// # let pin: GPIO = unimplemented!();
+/// let button = pin
+///     .configure_as_irq_input(InputMode::InPullUp, Flank::Falling, count_press)
+///     .unwrap();
+/// // ...
+/// let count = PRESSES.load(Ordering::Relaxed);
+/// ```
+#[macro_export]
+macro_rules! static_handler {
+    (
+        $svis:vis static $state:ident: $type:ty = $init:expr;
+        $fvis:vis fn $name:ident($arg:ident) $body:block
+    ) => {
+        $svis static $state: $type = $init;
+
+        $fvis fn $name() {
+            let $arg: &'static $type = &$state;
+            $body
+        }
+    };
+}
+
+/// A [GPIO] configured for input with an interrupt callback
+///
+/// The interrupt is disabled when this is dropped or [deconfigured](Self::deconfigured).
+pub struct IrqGPIO(GPIO);
+
+impl IrqGPIO {
+    /// See [GPIO::to_c]
+    pub fn to_c(&self) -> riot_sys::gpio_t {
+        self.0.to_c()
+    }
+
+    /// Disable the interrupt, and lose information about how the pin is configured, making it
+    /// configurable again
+    pub fn deconfigured(self) -> GPIO {
+        self.disable();
+        TAKEN.release(self.to_c());
+        let gpio = GPIO(self.to_c());
+        core::mem::forget(self);
+        gpio
+    }
+
+    /// Resume calling the callback on signal changes
+    #[doc(alias = "gpio_irq_enable")]
+    pub fn enable(&self) {
+        // unsafe: C API on a pin configured for interrupts
+        unsafe { riot_sys::gpio_irq_enable(self.to_c()) }
+    }
+
+    /// Stop calling the callback on signal changes
+    ///
+    /// Signal changes that happen while disabled may or may not trigger the callback once the
+    /// interrupt is enabled again, depending on the platform.
+    #[doc(alias = "gpio_irq_disable")]
+    pub fn disable(&self) {
+        // unsafe: C API on a pin configured for interrupts
+        unsafe { riot_sys::gpio_irq_disable(self.to_c()) }
+    }
+
+    pub fn is_high(&self) -> bool {
+        unsafe { gpio_read(self.to_c()) != 0 }
+    }
+
+    pub fn is_low(&self) -> bool {
+        unsafe { gpio_read(self.to_c()) == 0 }
+    }
+}
+
+impl Drop for IrqGPIO {
+    fn drop(&mut self) {
+        self.disable();
+        TAKEN.release(self.to_c());
+    }
+}
@@ -1,10 +1,16 @@
 //! Access to [RIOT's GPIO pins](http://doc.riot-os.org/group__drivers__periph__gpio.html)
 //!
-//! The various configured GPIO types ([InputGPIO], [OutputGPIO], [InOutGPIO]) can be used through
-//! the [embedded_hal::digital] traits. As recommended for infallible types, they also
-//! provide identically named direct methods, which (for input pins) also work on shared reference.
+//! The various configured GPIO types ([InputGPIO], [OutputGPIO], [InOutGPIO], and `IrqGPIO` for
+//! inputs with interrupts) can be used through the [embedded_hal::digital] traits. As recommended
+//! for infallible types, they also provide identically named direct methods, which (for input
+//! pins) also work on shared reference.
 
 mod impl_1;
+#[cfg(riot_module_periph_gpio_irq)]
+mod irq;
+
+#[cfg(riot_module_periph_gpio_irq)]
+pub use irq::{Flank, IrqGPIO};
 
 use riot_sys::{gpio_clear, gpio_mode_t, gpio_read, gpio_set, gpio_t, gpio_toggle, gpio_write};
 
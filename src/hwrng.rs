@@ -0,0 +1,58 @@
+//! Access to the [hardware random number generator](https://doc.riot-os.org/group__drivers__periph__hwrng.html)
+//!
+//! On platforms without one, the system's pseudo random number generator is available through
+//! [crate::random::Random].
+
+/// The hardware random number generator
+///
+/// Random data is obtained through its implementation of [rand_core_06::RngCore]. As the data
+/// comes from a physical source (and not from a seeded algorithm), it also implements
+/// [rand_core_06::CryptoRng].
+///
+/// Like [crate::random::Random], this is Copy, as the generator's state is global.
+#[derive(Copy, Clone, Debug)]
+#[doc(alias = "hwrng_read")]
+pub struct HwRng(());
+
+impl HwRng {
+    /// Initialize and access the hardware random number generator
+    ///
+    /// Initialization is usually performed at startup already, but repeating it is harmless.
+    #[doc(alias = "hwrng_init")]
+    pub fn new() -> Self {
+        // unsafe: C API makes no requirements
+        unsafe { riot_sys::hwrng_init() };
+        HwRng(())
+    }
+}
+
+impl Default for HwRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl rand_core_06::RngCore for HwRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut result = [0; 4];
+        self.fill_bytes(&mut result);
+        u32::from_ne_bytes(result)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut result = [0; 8];
+        self.fill_bytes(&mut result);
+        u64::from_ne_bytes(result)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // unsafe: C API on a buffer whose length is passed along; the type ensures it's initialized
+        unsafe { riot_sys::hwrng_read(dest.as_mut_ptr() as _, dest.len() as _) };
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core_06::Error> {
+        Ok(self.fill_bytes(dest))
+    }
+}
+
+impl rand_core_06::CryptoRng for HwRng {}
@@ -1,6 +1,6 @@
 use embedded_hal::i2c;
 
-use riot_sys::{i2c_acquire, i2c_read_bytes, i2c_release, i2c_write_bytes};
+use riot_sys::{i2c_acquire, i2c_read_bytes, i2c_read_regs, i2c_release, i2c_write_bytes};
 
 use crate::error::{NegativeErrorExt, NumericError};
 
@@ -8,6 +8,7 @@ use super::*;
 
 const I2C_NOSTOP: u8 = riot_sys::i2c_flags_t_I2C_NOSTOP;
 const I2C_NOSTART: u8 = riot_sys::i2c_flags_t_I2C_NOSTART;
+const I2C_REG16: u8 = riot_sys::i2c_flags_t_I2C_REG16;
 
 #[derive(Debug)]
 pub struct Error(NumericError);
@@ -47,6 +48,47 @@ fn with_acquire<R>(dev: &mut I2CDevice, f: impl FnOnce(&mut I2CDevice) -> R) ->
 }
 
 impl i2c::I2c<i2c::SevenBitAddress> for I2CDevice {
+    /// Write a register address and read from the device in one transaction
+    ///
+    /// Writes of 1 or 2 bytes (the common register access patterns) are performed through
+    /// `i2c_read_regs`, which drivers may implement more efficiently; 2 byte writes are sent as a
+    /// 16-bit register address in the order given. Other writes go through [Self::transaction].
+    fn write_read(
+        &mut self,
+        address: i2c::SevenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let (reg, flags) = match *write {
+            [reg] => (reg.into(), 0),
+            [high, low] => (u16::from_be_bytes([high, low]), I2C_REG16),
+            _ => {
+                return self.transaction(
+                    address,
+                    &mut [i2c::Operation::Write(write), i2c::Operation::Read(read)],
+                )
+            }
+        };
+        if read.is_empty() {
+            return self.transaction(address, &mut [i2c::Operation::Write(write)]);
+        }
+        with_acquire(self, |dev| {
+            // unsafe: C API on an acquired bus, with a buffer whose length is passed along
+            (unsafe {
+                i2c_read_regs(
+                    dev.dev,
+                    address as u16,
+                    reg,
+                    read.as_mut_ptr() as _,
+                    read.len() as _,
+                    flags,
+                )
+            })
+            .negative_to_error()?;
+            Ok(())
+        })
+    }
+
     fn transaction(
         &mut self,
         address: i2c::SevenBitAddress,
@@ -8,8 +8,14 @@ use riot_sys::i2c_t;
 ///
 /// [I2C implementation]: http://doc.riot-os.org/group__drivers__periph__i2c.html
 ///
-/// Actual transactions on this are performed through the [embedded_hal_0_2::blocking::i2c] traits
+/// Actual transactions on this are performed through the [embedded_hal::i2c::I2c] trait
 /// implemented by this.
+///
+/// The device can be any `i2c_t` the board provides, no matter whether the bus is driven by a
+/// hardware peripheral or bit-banged in software: Both are accessed through RIOT's `periph_i2c`
+/// API. Which implementation backs a given bus is decided at build time, by the board's
+/// configuration and the selected modules (eg. the CPU's `periph_i2c`, or a GPIO based
+/// implementation like `periph_i2c_gpio` where the platform offers one).
 #[derive(Debug)]
 pub struct I2CDevice {
     dev: i2c_t,
@@ -75,3 +75,35 @@ pub fn free<R, F: FnOnce(&CriticalSection) -> R>(f: F) -> R {
     unsafe { riot_sys::irq_restore(stored) };
     ret
 }
+
+/// A guard that keeps interrupts disabled until it is dropped
+///
+/// This is the scope-less alternative to [free]. Unlike that function, it does not provide a
+/// [CriticalSection] token: As guards can be dropped in any order, an inner guard could outlive
+/// the outer guard that re-enables the interrupts, and any token derived from it would then
+/// falsely attest a critical section. Where a token is needed, use [free].
+///
+/// The guard can not be sent to other threads, as the interrupt state it restores is that of the
+/// current context.
+#[doc(alias = "irq_disable")]
+pub struct CriticalSectionGuard {
+    stored: riot_sys::libc::c_uint,
+    _not_send: core::marker::PhantomData<*const ()>,
+}
+
+impl CriticalSectionGuard {
+    /// Disable interrupts until the returned guard is dropped
+    pub fn enter() -> Self {
+        CriticalSectionGuard {
+            stored: unsafe { riot_sys::irq_disable() },
+            _not_send: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Drop for CriticalSectionGuard {
+    #[doc(alias = "irq_restore")]
+    fn drop(&mut self) {
+        unsafe { riot_sys::irq_restore(self.stored) };
+    }
+}
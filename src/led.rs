@@ -6,7 +6,8 @@ use core::convert::Infallible;
 ///
 /// The preferred interface for turning a LED on and off is [switch_hal::OutputSwitch].
 ///
-/// LEDs are accessible safely; any not implemented on a board are silently ignored.
+/// LEDs are accessible safely; when obtained through [LED::new_unchecked], any not implemented on
+/// a board are silently ignored.
 #[derive(Debug)]
 pub struct LED<const I: u8>(());
 
@@ -15,12 +16,14 @@ pub struct LED<const I: u8>(());
 pub struct LedNotPresent;
 
 impl<const I: u8> LED<I> {
-    #[deprecated(
-        note = "Use new_unchecked() to retain the behavior this function has always had; future versions of `.new()` will panic when used with a board that does not have that LED.",
-        since = "0.9.1"
-    )]
+    /// Accesses the LED numbered `I` as `LED::<I>::new()`.
+    ///
+    /// Whether the LED is present on the board is known at build time; using this with a LED the
+    /// board does not have is a build error. Use [Self::new_checked] for code that needs to adapt
+    /// to the board, or [Self::new_unchecked] to silently ignore missing LEDs.
     pub const fn new() -> Self {
-        Self::new_unchecked()
+        const { assert!(Self::is_present(), "LED is not present on this board") };
+        Self(())
     }
 
     /// Accesses the LED numbered `I` as `LED::<I>::new_unchecked()`.
@@ -4,6 +4,14 @@
 //! crate's README file] on general concepts (such as the interaction between modules here, RIOT
 //! modules and features), and the individual modules' documentation entries for details.
 //!
+//! ## Availability of wrappers
+//!
+//! Wrappers are only built when the RIOT modules they wrap are part of the build: The build
+//! script reads the enabled modules from `riotbuild.h` and sets a `riot_module_<name>` cfg for
+//! each (eg. `riot_module_gcoap`), by which the wrappers are gated. Using a wrapper of a module
+//! that is not enabled thus fails at compile time, pointing to the missing item, rather than
+//! failing to link. Enabling it is done in the application's Makefile (eg. `USEMODULE += gcoap`).
+//!
 //! [RIOT-OS]: https://www.riot-os.org/
 //! [RIOT's documentation on using Rust]: https://doc.riot-os.org/using-rust.html
 //! [this crate's README file]: https://github.com/RIOT-OS/rust-riot-wrappers
@@ -41,18 +49,21 @@ mod never;
 
 /// The identifier of the RIOT board the program is being built for (`RIOT_BOARD` in C).
 #[doc(alias = "RIOT_BOARD")]
-pub const BOARD: &'static str = {
-    let b = riot_sys::RIOT_BOARD;
+pub const BOARD: &'static str = build_string(riot_sys::RIOT_BOARD);
+
+/// Convert one of the string constants from `riotbuild.h` (which contain a terminating null byte)
+/// into a `&str`
+const fn build_string(b: &'static [u8]) -> &'static str {
     let Ok(b) = core::ffi::CStr::from_bytes_with_nul(b) else {
         // Could be `.expect()`, but that's not const yet
         // Workaround-For: https://github.com/rust-lang/rust/issues/67441
-        panic!("Board names are null-terminated C strings");
+        panic!("Build strings are null-terminated C strings");
     };
     let Ok(b) = b.to_str() else {
-        panic!("Board names should be ASCII")
+        panic!("Build strings should be ASCII")
     };
     b
-};
+}
 
 #[inline]
 const fn assert_same_layout<A, B>() {
@@ -112,6 +123,8 @@ unsafe fn inline_cast_ref_mut<A, B>(input: &mut A) -> &mut B {
     core::mem::transmute(input)
 }
 
+pub mod board;
+pub mod log;
 #[cfg(riot_module_saul)]
 pub mod saul;
 #[cfg(riot_module_shell)]
@@ -134,21 +147,54 @@ pub mod msg;
 #[cfg(riot_module_random)]
 pub mod random;
 
+#[cfg(all(feature = "with_dhcpv6", riot_module_dhcpv6_client, riot_module_gnrc))]
+pub mod dhcpv6;
+
 #[cfg(riot_module_periph_spi)]
 pub mod spi;
 
 #[cfg(riot_module_periph_adc)]
 pub mod adc;
 
+#[cfg(all(feature = "with_at_parser", riot_module_at))]
+pub mod at;
+
 #[cfg(riot_module_periph_dac)]
 pub mod dac;
 
+#[cfg(riot_module_periph_flashpage)]
+pub mod flashpage;
+
+#[cfg(riot_module_periph_hwrng)]
+pub mod hwrng;
+
+#[cfg(riot_module_mtd)]
+pub mod mtd;
+
+#[cfg(riot_module_periph_flashpage)]
+pub mod storage;
+
+#[cfg(riot_module_periph_pm)]
+pub mod pm;
+
+#[cfg(riot_module_periph_rtc)]
+pub mod rtc;
+
+#[cfg(riot_module_periph_wdt)]
+pub mod wdt;
+
 #[cfg(riot_module_ztimer)]
 pub mod ztimer;
 
 pub mod mutex;
+pub mod once;
+pub mod ringbuffer;
 #[cfg(riot_module_pthread)]
 pub mod rwlock;
+#[cfg(riot_module_tsrb)]
+pub mod tsrb;
+pub mod util;
+pub mod version;
 
 #[cfg(feature = "set_panic_handler")]
 mod panic;
@@ -157,17 +203,27 @@ mod panic;
 pub mod coap_handler;
 #[cfg(riot_module_gcoap)]
 pub mod coap_message;
+#[cfg(all(riot_module_gcoap, riot_module_sock_udp))]
+pub mod coap_observe;
 
 #[cfg(riot_module_sock)]
 pub mod socket;
 #[cfg(all(riot_module_sock_udp, feature = "with_embedded_nal"))]
 pub mod socket_embedded_nal;
+#[cfg(all(
+    riot_module_sock_tcp,
+    riot_module_sock_async,
+    feature = "with_embedded_nal_async"
+))]
+pub mod socket_embedded_nal_async_tcp;
 #[cfg(all(
     riot_module_sock_udp,
     riot_module_sock_aux_local,
     feature = "with_embedded_nal_async"
 ))]
 pub mod socket_embedded_nal_async_udp;
+#[cfg(all(riot_module_sock_dns, feature = "with_embedded_nal"))]
+pub mod socket_embedded_nal_dns;
 #[cfg(all(riot_module_sock_tcp, feature = "with_embedded_nal"))]
 pub mod socket_embedded_nal_tcp;
 
@@ -201,10 +257,17 @@ pub mod led;
 #[cfg(riot_module_auto_init)]
 pub mod auto_init;
 
-// Gated like socket_embedded_nal_async_udp as it is only used there -- expand as needed.
-#[cfg(all(
-    riot_module_sock_udp,
-    riot_module_sock_aux_local,
-    feature = "with_embedded_nal_async"
+// Gated like socket_embedded_nal_async_{udp,tcp} as it is only used there -- expand as needed.
+#[cfg(any(
+    all(
+        riot_module_sock_udp,
+        riot_module_sock_aux_local,
+        feature = "with_embedded_nal_async"
+    ),
+    all(
+        riot_module_sock_tcp,
+        riot_module_sock_async,
+        feature = "with_embedded_nal_async"
+    )
 ))]
 mod async_helpers;
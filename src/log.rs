@@ -0,0 +1,126 @@
+//! Leveled logging in the style of RIOT's [log module](https://doc.riot-os.org/group__core__util.html)
+//!
+//! The [log_error], [log_warning], [log_info] and [log_debug] macros correspond to C's
+//! `LOG_ERROR` etc.: They print a line if the message's level is enabled by the `LOG_LEVEL` the
+//! application is built with (eg. through `CFLAGS += -DLOG_LEVEL=LOG_DEBUG`; it defaults to
+//! `LOG_INFO`). Messages of disabled levels are not formatted, and the compiler removes them.
+//!
+//! Each message is formatted into a buffer on the stack and written out in one piece, so lines of
+//! different threads do not get mixed up. Lines longer than [MAX_LINE_LEN] are truncated.
+//!
+//! Like C's default `log_write`, this goes to stdio; log backends selected through modules (like
+//! `log_color`) are not used.
+//!
+//! ```no_run
+//! # use riot_wrappers::{log_info, log_debug};
+//! log_info!("Starting up on {}", riot_wrappers::BOARD);
+//! log_debug!("Not shown unless built with LOG_LEVEL=LOG_DEBUG");
+//! ```
+
+use core::fmt::Write;
+
+/// Severity of a log message, in the order of the C `log_level_t` values
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[doc(alias = "log_level_t")]
+pub enum Level {
+    Error = 1,
+    Warning = 2,
+    Info = 3,
+    Debug = 4,
+}
+
+/// The `LOG_LEVEL` the application is built with, expressed as the number of the most verbose
+/// level that is shown (0 for `LOG_NONE`, 5 for `LOG_ALL`)
+const MAX_LEVEL: u8 = if cfg!(riot_log_level = "none") {
+    0
+} else if cfg!(riot_log_level = "error") {
+    1
+} else if cfg!(riot_log_level = "warning") {
+    2
+} else if cfg!(riot_log_level = "debug") {
+    4
+} else if cfg!(riot_log_level = "all") {
+    5
+} else {
+    3
+};
+
+/// Longest line (not counting the newline) that is written by the logging macros
+pub const MAX_LINE_LEN: usize = 127;
+
+impl Level {
+    /// Whether messages of this level are shown with the `LOG_LEVEL` the application is built
+    /// with
+    pub const fn enabled(self) -> bool {
+        self as u8 <= MAX_LEVEL
+    }
+}
+
+/// Writer into a fixed buffer that discards what does not fit
+struct Truncating {
+    buf: [u8; MAX_LINE_LEN + 1],
+    len: usize,
+}
+
+impl Write for Truncating {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let mut take = s.len().min(MAX_LINE_LEN - self.len);
+        // Not splitting UTF-8 sequences
+        while !s.is_char_boundary(take) {
+            take -= 1;
+        }
+        self.buf[self.len..self.len + take].copy_from_slice(&s.as_bytes()[..take]);
+        self.len += take;
+        Ok(())
+    }
+}
+
+/// Write a line for the logging macros, which check the level before calling this
+#[doc(hidden)]
+pub fn write_line(args: core::fmt::Arguments) {
+    let mut line = Truncating {
+        buf: [0; MAX_LINE_LEN + 1],
+        len: 0,
+    };
+    let _ = line.write_fmt(args);
+    line.buf[line.len] = b'\n';
+    // unsafe: C API on a valid buffer
+    unsafe { riot_sys::stdio_write(line.buf.as_ptr() as _, (line.len + 1) as _) };
+}
+
+/// Log a message at the given [Level]
+#[macro_export]
+macro_rules! log_at {
+    ($level:expr, $($arg:tt)+) => {{
+        let level: $crate::log::Level = $level;
+        if level.enabled() {
+            $crate::log::write_line(format_args!($($arg)+));
+        }
+    }};
+}
+
+/// Log a message at the [Error](Level::Error) level (like C's `LOG_ERROR`)
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Error, $($arg)+) };
+}
+
+/// Log a message at the [Warning](Level::Warning) level (like C's `LOG_WARNING`)
+#[macro_export]
+macro_rules! log_warning {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Warning, $($arg)+) };
+}
+
+/// Log a message at the [Info](Level::Info) level (like C's `LOG_INFO`)
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Info, $($arg)+) };
+}
+
+/// Log a message at the [Debug](Level::Debug) level (like C's `LOG_DEBUG`)
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)+) => { $crate::log_at!($crate::log::Level::Debug, $($arg)+) };
+}
+
+pub use {log_at, log_debug, log_error, log_info, log_warning};
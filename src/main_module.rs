@@ -8,6 +8,30 @@
 //! In these cases, Rust code can be called into from the main C code by declaring the entry
 //! functions `#[no_mangle] pub extern "C"`, and having analogous `extern` functions in the calling
 //! C code.
+//!
+//! ## Panics in main
+//!
+//! The panic handler is not part of the macro: It is provided by this crate when the
+//! `set_panic_handler` feature is enabled. With that, a panic in main (or any other thread) stops
+//! only the panicking thread; the `panic_handler_format` feature makes it print the panic message
+//! through stdio first, and `panic_handler_crash` makes any panic halt the system through
+//! `core_panic` (which, depending on the board's configuration, reboots it).
+//!
+//! A minimal application thus consists of the crate attributes, the macro and the main function:
+//!
+//! ```no_run
+//! #![no_std]
+//! #![no_main]
+//! // with these features of riot-wrappers in Cargo.toml:
+//! // features = [ "set_panic_handler", "panic_handler_format" ]
+//!
+//! riot_wrappers::riot_main!(main);
+//!
+//! fn main() -> Result<(), &'static str> {
+//!     riot_wrappers::println!("Hello from {}", riot_wrappers::BOARD);
+//!     Err("nothing more to do")
+//! }
+//! ```
 
 use crate::stdio::println;
 use crate::thread::{EndToken, StartToken};
@@ -0,0 +1,243 @@
+//! Publish-subscribe messaging through RIOT's [message bus](https://doc.riot-os.org/group__core__msg__bus.html)
+//!
+//! A [MsgBus] carries values of a single type. Any thread (or interrupt) can post to it through a
+//! shared reference; threads that created a [Subscriber] receive the values posted for the event
+//! types they subscribed to.
+//!
+//! ```no_run
+//! # use core::pin::pin;
+//! # use riot_wrappers::msg::bus::{MsgBus, Subscriber};
+//! # let bus: MsgBus<u32> = MsgBus::new();
+//! const TEMPERATURE: u8 = 1;
+//!
+//! let mut subscriber = pin!(Subscriber::new(&bus));
+//! subscriber.as_mut().subscribe(TEMPERATURE);
+//! // ... and elsewhere, bus.post(TEMPERATURE, 2150);
+//! let (event, value) = subscriber.as_mut().recv();
+//! ```
+
+use core::cell::UnsafeCell;
+use core::marker::{PhantomData, PhantomPinned};
+use core::pin::Pin;
+
+use pin_project::{pin_project, pinned_drop};
+
+use crate::thread::KernelPID;
+
+/// Number of distinct event types on a bus (they are numbered from 0 to 31)
+pub const EVENT_TYPES: u8 = 32;
+
+/// A message bus transporting values of type `T`
+///
+/// `T` needs to fit into a message's content (which is the size of a pointer).
+#[repr(transparent)]
+pub struct MsgBus<T: Copy + Send> {
+    bus: UnsafeCell<riot_sys::msg_bus_t>,
+    _phantom: PhantomData<T>,
+}
+
+// unsafe: All accesses to the bus go through C functions that disable interrupts around their
+// modifications.
+unsafe impl<T: Copy + Send> Sync for MsgBus<T> {}
+// unsafe: The bus does not refer to any thread (the subscribers, which do, borrow it)
+unsafe impl<T: Copy + Send> Send for MsgBus<T> {}
+
+impl<T: Copy + Send> MsgBus<T> {
+    const FITS: () = {
+        assert!(
+            core::mem::size_of::<T>() <= core::mem::size_of::<riot_sys::msg_t__bindgen_ty_1>(),
+            "Type is too large to be transported in a message"
+        );
+        assert!(
+            core::mem::align_of::<T>() <= core::mem::align_of::<riot_sys::msg_t__bindgen_ty_1>(),
+            "Type has stricter alignment requirements than the message content"
+        );
+    };
+
+    /// Create an empty bus
+    #[doc(alias = "msg_bus_init")]
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)] // reason: evaluating the constant performs the checks
+        let () = Self::FITS;
+
+        let mut bus = core::mem::MaybeUninit::uninit();
+        // unsafe: C function initializes the struct. The bus is not yet in use, so it may still
+        // move; no subscriber can be attached before it is borrowed, which keeps it in place.
+        unsafe { riot_sys::msg_bus_init(bus.as_mut_ptr()) };
+        MsgBus {
+            bus: UnsafeCell::new(unsafe { bus.assume_init() }),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Use a bus that is initialized and owned by C code (eg. one of a network interface's buses)
+    ///
+    /// # Safety
+    ///
+    /// The bus needs to be initialized and valid for the lifetime `'a`, and all values posted to
+    /// it (including by C code) need to be valid values of `T`.
+    pub unsafe fn from_ptr<'a>(bus: *mut riot_sys::msg_bus_t) -> &'a Self {
+        #[allow(clippy::let_unit_value)] // reason: evaluating the constant performs the checks
+        let () = Self::FITS;
+        // unsafe: The type is transparent around the bus, and validity is up to the caller
+        unsafe { &*(bus as *const Self) }
+    }
+
+    /// Send a value to all threads subscribed to the event type, returning the number of threads
+    /// it was delivered to
+    ///
+    /// Posting does not block; threads that can not take the message right away (because their
+    /// message queue is full, or they have none and are not waiting) miss it.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the event type is not below [EVENT_TYPES].
+    #[doc(alias = "msg_bus_post")]
+    pub fn post(&self, event: u8, value: T) -> usize {
+        assert!(event < EVENT_TYPES, "Event type out of range");
+        let mut content = riot_sys::msg_t__bindgen_ty_1 { value: 0 };
+        // unsafe: Size and alignment were checked when the bus was created
+        unsafe { core::ptr::write(&mut content as *mut _ as *mut T, value) };
+        // unsafe: C API on an initialized bus; the pointer is not dereferenced but copied into the
+        // message content.
+        let delivered = unsafe {
+            riot_sys::inline::msg_bus_post(
+                crate::inline_cast_mut(self.bus.get()),
+                event,
+                content.ptr as *const _,
+            )
+        };
+        delivered as _
+    }
+}
+
+impl<T: Copy + Send> Default for MsgBus<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A thread's subscription to a [MsgBus]
+///
+/// As the bus keeps track of its subscribers in a linked list, the subscriber can not move while
+/// it is attached; `pin!(Subscriber::new(&bus))` will do to get a usable one. It is attached to the
+/// bus for the thread it is used in when [Self::subscribe] is first called, and detached when
+/// dropped.
+///
+/// ## Invariants
+///
+/// If the entry is present, it is attached to the bus for the thread `pid`.
+#[pin_project(PinnedDrop)]
+pub struct Subscriber<'b, T: Copy + Send> {
+    bus: &'b MsgBus<T>,
+    #[pin]
+    entry: Option<(riot_sys::msg_bus_entry_t, KernelPID)>,
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+impl<'b, T: Copy + Send> Subscriber<'b, T> {
+    /// Prepare a subscription to the bus, which is not attached yet
+    pub fn new(bus: &'b MsgBus<T>) -> Self {
+        Subscriber {
+            bus,
+            entry: None,
+            _pin: PhantomPinned,
+        }
+    }
+
+    /// Receive values posted for the event type
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the event type is not below [EVENT_TYPES], or if the subscriber was already
+    /// attached in a different thread.
+    #[doc(alias = "msg_bus_attach")]
+    #[doc(alias = "msg_bus_subscribe")]
+    pub fn subscribe(self: Pin<&mut Self>, event: u8) {
+        assert!(event < EVENT_TYPES, "Event type out of range");
+        let entry = self.attached_entry();
+        // unsafe: C API on an attached entry; the function just sets a bit in the entry's mask,
+        // and the entry is only read by the bus with interrupts disabled.
+        unsafe { riot_sys::inline::msg_bus_subscribe(crate::inline_cast_mut(entry), event) };
+    }
+
+    /// Stop receiving values posted for the event type
+    #[doc(alias = "msg_bus_unsubscribe")]
+    pub fn unsubscribe(self: Pin<&mut Self>, event: u8) {
+        assert!(event < EVENT_TYPES, "Event type out of range");
+        let entry = self.attached_entry();
+        // unsafe: as in subscribe()
+        unsafe { riot_sys::inline::msg_bus_unsubscribe(crate::inline_cast_mut(entry), event) };
+    }
+
+    /// Block until a value is posted for any of the subscribed event types, and return the event
+    /// type and value
+    ///
+    /// Messages that do not come from the bus are discarded, so this is best used in threads
+    /// that receive no other messages.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if called from another thread than the one the subscriber was attached in.
+    #[doc(alias = "msg_receive")]
+    pub fn recv(self: Pin<&mut Self>) -> (u8, T) {
+        let bus = self.bus.bus.get();
+        self.attached_entry();
+        loop {
+            let mut msg = core::mem::MaybeUninit::uninit();
+            // unsafe: C API, the message is initialized after return
+            let msg = unsafe {
+                riot_sys::msg_receive(msg.as_mut_ptr());
+                msg.assume_init()
+            };
+            // unsafe: Side effect free C function on an initialized bus
+            if !unsafe {
+                riot_sys::inline::msg_is_from_bus(crate::inline_cast(bus), crate::inline_cast(&msg))
+            } {
+                continue;
+            }
+            // unsafe: Side effect free C function
+            let event = unsafe { riot_sys::inline::msg_bus_get_type(crate::inline_cast(&msg)) };
+            // unsafe: All messages from this bus were posted with a T in them
+            let value = unsafe { core::ptr::read(&msg.content as *const _ as *const T) };
+            return (event as _, value);
+        }
+    }
+
+    /// Attach the entry if it is not attached yet, and return a pointer to it
+    fn attached_entry(self: Pin<&mut Self>) -> *mut riot_sys::msg_bus_entry_t {
+        let pid = crate::thread::get_pid();
+        let bus = self.bus.bus.get();
+        // unsafe: The entry is not moved out of its pinned place
+        let entry = unsafe { Pin::into_inner_unchecked(self.project().entry) };
+        match entry {
+            Some((entry, attached_pid)) => {
+                assert!(
+                    *attached_pid == pid,
+                    "Subscriber used outside the thread it was attached in"
+                );
+                entry
+            }
+            None => {
+                // unsafe: All-zero is a valid detached entry, which msg_bus_attach initializes
+                let (entry, _) = entry.insert((unsafe { core::mem::zeroed() }, pid));
+                // unsafe: C API; the entry is in its final pinned place, and detached on drop.
+                unsafe { riot_sys::msg_bus_attach(bus, entry) };
+                entry
+            }
+        }
+    }
+}
+
+#[pinned_drop]
+impl<T: Copy + Send> PinnedDrop for Subscriber<'_, T> {
+    fn drop(self: Pin<&mut Self>) {
+        let bus = self.bus.bus.get();
+        let this = self.project();
+        // unsafe: The entry is removed from the bus's list before it is dropped
+        if let Some((entry, _)) = unsafe { Pin::into_inner_unchecked(this.entry) } {
+            unsafe { riot_sys::msg_bus_detach(bus, entry) };
+        }
+    }
+}
@@ -12,6 +12,8 @@ use core::mem::MaybeUninit;
 use riot_sys::libc;
 use riot_sys::{self, kernel_pid_t, msg_receive, msg_reply, msg_send, msg_send_receive, msg_t};
 
+#[cfg(riot_module_core_msg_bus)]
+pub mod bus;
 #[cfg(feature = "with_msg_v2")]
 pub mod v2;
 
@@ -106,6 +108,24 @@ impl OpaqueMsg {
         let _ = unsafe { msg_receive(m.as_mut_ptr()) };
         OpaqueMsg(unsafe { m.assume_init() })
     }
+
+    /// Block to receive a message, but only until the timeout has passed on the given clock
+    ///
+    /// Returns None if no message arrived in time.
+    #[cfg(riot_module_ztimer)]
+    #[doc(alias = "ztimer_msg_receive_timeout")]
+    pub fn receive_timeout<const HZ: u32>(
+        clock: &crate::thread::ValueInThread<crate::ztimer::Clock<HZ>>,
+        timeout: crate::ztimer::Ticks<HZ>,
+    ) -> Option<OpaqueMsg> {
+        let mut m: MaybeUninit<msg_t> = MaybeUninit::uninit();
+        // unsafe: C API, called in a thread as guaranteed by the ValueInThread
+        let result = unsafe {
+            riot_sys::ztimer_msg_receive_timeout(clock.as_ptr(), m.as_mut_ptr(), timeout.0)
+        };
+        // unsafe: Initialized by the C function on success
+        (result >= 0).then(|| OpaqueMsg(unsafe { m.assume_init() }))
+    }
 }
 
 impl ::core::fmt::Debug for OpaqueMsg {
@@ -269,6 +269,42 @@ pub trait MessageSemantics: Sized {
         }
     }
 
+    /// Block to receive a single message, but only until the timeout has passed on the given
+    /// clock
+    #[cfg(riot_module_ztimer)]
+    #[doc(alias = "ztimer_msg_receive_timeout")]
+    fn receive_timeout<const HZ: u32>(
+        &self,
+        clock: &thread::ValueInThread<crate::ztimer::Clock<HZ>>,
+        timeout: crate::ztimer::Ticks<HZ>,
+    ) -> Option<ReceivedMessage<'_, Self>> {
+        let mut msg = MaybeUninit::uninit();
+        if unsafe {
+            riot_sys::ztimer_msg_receive_timeout(clock.as_ptr(), msg.as_mut_ptr(), timeout.0)
+        } >= 0
+        {
+            let msg = unsafe { msg.assume_init() };
+            Some(ReceivedMessage {
+                msg,
+                _phantom: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Prepare to receive a single message, waiting at most `timeout_us` microseconds
+    ///
+    /// The timeout can still be changed on the returned builder before receiving, eg.
+    /// `semantics.recv_timeout(0).timeout_ms(100).recv()`.
+    #[cfg(riot_module_ztimer_usec)]
+    fn recv_timeout(&self, timeout_us: u32) -> RecvTimeout<'_, Self> {
+        RecvTimeout {
+            semantics: self,
+            timeout_us,
+        }
+    }
+
     /// Interpret a message according to these semantics, then drop it.
     ///
     ///
@@ -357,6 +393,85 @@ impl<TAIL: MessageSemantics, TYPE, const TYPENO: u16> MessageSemantics
 
 pub use crate::msg::MsgSender as Sender;
 
+/// Error returned when no message arrived before the timeout passed
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Timeout;
+
+/// A receive operation with a timeout, created through [MessageSemantics::recv_timeout]
+///
+/// Timeouts are measured on the microsecond clock; this must be used from the thread whose
+/// messages are received (as are the other receive methods).
+#[cfg(riot_module_ztimer_usec)]
+pub struct RecvTimeout<'a, S: MessageSemantics> {
+    semantics: &'a S,
+    timeout_us: u32,
+}
+
+#[cfg(riot_module_ztimer_usec)]
+impl<'a, S: MessageSemantics> RecvTimeout<'a, S> {
+    /// Set the timeout in milliseconds
+    ///
+    /// Timeouts beyond the range of the microsecond clock (about 71 minutes) are limited to it.
+    pub fn timeout_ms(self, timeout_ms: u32) -> Self {
+        self.timeout_us(timeout_ms.saturating_mul(1000))
+    }
+
+    /// Set the timeout in microseconds
+    pub fn timeout_us(self, timeout_us: u32) -> Self {
+        RecvTimeout { timeout_us, ..self }
+    }
+
+    /// Block to receive a single message, but only until the timeout has passed
+    #[doc(alias = "ztimer_msg_receive_timeout")]
+    pub fn recv(self) -> Result<ReceivedMessage<'a, S>, Timeout> {
+        self.semantics
+            .receive_timeout(
+                &crate::ztimer::Clock::usec(),
+                crate::ztimer::Ticks(self.timeout_us),
+            )
+            .ok_or(Timeout)
+    }
+
+    /// Block to receive a message for the given port, but only until the timeout has passed
+    ///
+    /// Messages of other types that arrive in the meantime are dropped as described by the
+    /// message semantics; waiting then continues for the rest of the timeout.
+    pub fn recv_on<T: Send, const TYPENO: u16>(
+        self,
+        port: &'a ReceivePort<T, TYPENO>,
+    ) -> Result<T, Timeout> {
+        let clock = crate::ztimer::Clock::usec();
+        let locked = clock.acquire();
+        let start = locked.now();
+        loop {
+            let remaining = self
+                .timeout_us
+                .checked_sub((locked.now() - start).0)
+                .ok_or(Timeout)?;
+            let message = self
+                .semantics
+                .receive_timeout(&clock, crate::ztimer::Ticks(remaining))
+                .ok_or(Timeout)?;
+            if let Ok(value) = message.decode(port, |_, value| value) {
+                return Ok(value);
+            }
+        }
+    }
+}
+
+/// Receive a message for the given port, waiting at most `timeout_us` microseconds
+///
+/// This is a shortcut for `semantics.recv_timeout(timeout_us).recv_on(port)`; see
+/// [RecvTimeout::recv_on].
+#[cfg(riot_module_ztimer_usec)]
+pub fn recv_timeout<S: MessageSemantics, T: Send, const TYPENO: u16>(
+    semantics: &S,
+    port: &ReceivePort<T, TYPENO>,
+    timeout_us: u32,
+) -> Result<T, Timeout> {
+    semantics.recv_timeout(timeout_us).recv_on(port)
+}
+
 /// A message that was received while S was the current thread's semantics.
 ///
 /// By including a lifetime argument this ensures that messages are decoded (or dropped) before
@@ -0,0 +1,136 @@
+//! Access to [Memory Technology Devices](https://doc.riot-os.org/group__drivers__mtd.html)
+//! such as external SPI flash chips
+//!
+//! An MTD is organized in sectors, which are the unit of erasing, and which consist of pages,
+//! which are the unit of writing. Writes only clear bits, so data is typically erased before it is
+//! written; [Mtd::write_page] does that automatically where available.
+//!
+//! With the `with_embedded_storage` feature, the device implements the `embedded-storage`
+//! `ReadStorage` trait, and (if `mtd_write_page` is available) the `Storage` trait.
+
+use crate::error::{NegativeErrorExt, NumericError};
+
+/// An MTD device
+///
+/// All accesses go through `&mut self`, as MTD drivers do not synchronize accesses on their own.
+#[derive(Debug)]
+pub struct Mtd(*mut riot_sys::mtd_dev_t);
+
+impl Mtd {
+    /// Wrap an MTD device (typically obtained through board defined macros like `MTD_0`)
+    ///
+    /// # Safety
+    ///
+    /// The device needs to be valid for the rest of the program, and may not be used through any
+    /// other means as long as the [Mtd] exists.
+    pub unsafe fn from_ptr(dev: *mut riot_sys::mtd_dev_t) -> Self {
+        Mtd(dev)
+    }
+
+    /// Initialize the device
+    ///
+    /// This is necessary before use, unless done already (eg. by `auto_init`).
+    #[doc(alias = "mtd_init")]
+    pub fn init(&mut self) -> Result<(), NumericError> {
+        // unsafe: C API on a valid device
+        (unsafe { riot_sys::mtd_init(self.0) })
+            .negative_to_error()
+            .map(|_| ())
+    }
+
+    /// Number of sectors on the device
+    pub fn sector_count(&self) -> u32 {
+        unsafe { (*self.0).sector_count }
+    }
+
+    /// Number of pages in a sector
+    pub fn pages_per_sector(&self) -> u32 {
+        unsafe { (*self.0).pages_per_sector }
+    }
+
+    /// Size of a page, in bytes
+    pub fn page_size(&self) -> u32 {
+        unsafe { (*self.0).page_size }
+    }
+
+    /// Size of a sector, in bytes
+    pub fn sector_size(&self) -> u32 {
+        self.pages_per_sector() * self.page_size()
+    }
+
+    /// Total size of the device, in bytes
+    pub fn capacity(&self) -> u64 {
+        self.sector_count() as u64 * self.sector_size() as u64
+    }
+
+    /// Read data starting at the given address
+    #[doc(alias = "mtd_read")]
+    pub fn read(&mut self, addr: u32, buf: &mut [u8]) -> Result<(), NumericError> {
+        let count = buf.len().try_into().map_err(|_| crate::error::EOVERFLOW)?;
+        // unsafe: C API on a valid device, with a buffer whose length is passed along (range
+        // checks are done by the C function)
+        (unsafe { riot_sys::mtd_read(self.0, buf.as_mut_ptr() as _, addr, count) })
+            .negative_to_error()
+            .map(|_| ())
+    }
+
+    /// Write data starting at the given address, without erasing
+    ///
+    /// The written range may not cross a page boundary, and needs to be erased before.
+    #[doc(alias = "mtd_write")]
+    pub fn write(&mut self, addr: u32, data: &[u8]) -> Result<(), NumericError> {
+        let count = data.len().try_into().map_err(|_| crate::error::EOVERFLOW)?;
+        // unsafe: C API on a valid device, with a buffer whose length is passed along (range
+        // checks are done by the C function)
+        (unsafe { riot_sys::mtd_write(self.0, data.as_ptr() as _, addr, count) })
+            .negative_to_error()
+            .map(|_| ())
+    }
+
+    /// Write data starting at `offset` bytes into the given page, erasing sectors as needed
+    ///
+    /// Unlike [Self::write], the written range may span any number of pages. Data in the erased
+    /// sectors outside the written range is preserved.
+    #[cfg(riot_module_mtd_write_page)]
+    #[doc(alias = "mtd_write_page")]
+    pub fn write_page(&mut self, page: u32, offset: u32, data: &[u8]) -> Result<(), NumericError> {
+        let count = data.len().try_into().map_err(|_| crate::error::EOVERFLOW)?;
+        // unsafe: C API on a valid device, with a buffer whose length is passed along (range
+        // checks are done by the C function)
+        (unsafe { riot_sys::mtd_write_page(self.0, data.as_ptr() as _, page, offset, count) })
+            .negative_to_error()
+            .map(|_| ())
+    }
+
+    /// Erase the given range of bytes
+    ///
+    /// Address and length need to be multiples of the [sector size](Self::sector_size).
+    #[doc(alias = "mtd_erase")]
+    pub fn erase(&mut self, addr: u32, count: u32) -> Result<(), NumericError> {
+        // unsafe: C API on a valid device (alignment and range checks are done by the C function)
+        (unsafe { riot_sys::mtd_erase(self.0, addr, count) })
+            .negative_to_error()
+            .map(|_| ())
+    }
+}
+
+#[cfg(feature = "with_embedded_storage")]
+impl embedded_storage::ReadStorage for Mtd {
+    type Error = NumericError;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), NumericError> {
+        Mtd::read(self, offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        Mtd::capacity(self).try_into().unwrap_or(usize::MAX)
+    }
+}
+
+#[cfg(all(feature = "with_embedded_storage", riot_module_mtd_write_page))]
+impl embedded_storage::Storage for Mtd {
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), NumericError> {
+        let page_size = self.page_size();
+        self.write_page(offset / page_size, offset % page_size, bytes)
+    }
+}
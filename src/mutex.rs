@@ -19,8 +19,6 @@ use core::cell::UnsafeCell;
 /// threads don't unwind Rust code. As a consequence, the mutex interface is different from the
 /// standard library's.
 ///
-/// Several methods (into_inner, get_mut) are not implemented until they're actually needed.
-///
 /// [std::sync::mutex]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
 pub struct Mutex<T> {
     mutex: UnsafeCell<riot_sys::inline::mutex_t>,
@@ -65,6 +63,18 @@ impl<T> Mutex<T> {
         }
     }
 
+    /// Access the inner data through an exclusive reference
+    ///
+    /// As the mutex is borrowed exclusively, this needs no locking.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+
+    /// Consume the mutex, returning its inner data
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+
     /// Lock the mutex and throw away the key
     ///
     /// Try to lock the mutex (returning None if it is locked). When successful, a mutable
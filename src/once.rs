@@ -0,0 +1,146 @@
+//! One-time initialization built on RIOT's [mutex](crate::mutex)
+//!
+//! This roughly mimics [std::sync::Once] and [std::sync::OnceLock], and is typically used for
+//! global values that are set up by whichever thread needs them first.
+//!
+//! [std::sync::Once]: https://doc.rust-lang.org/std/sync/struct.Once.html
+//! [std::sync::OnceLock]: https://doc.rust-lang.org/std/sync/struct.OnceLock.html
+
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::mutex::Mutex;
+
+/// A cell that is written to at most once
+///
+/// Unlike [std::sync::OnceLock], this has no concept of poisoning; if an initializing closure
+/// panics, any other thread trying to initialize the cell blocks forever (see
+/// [Mutex](crate::mutex::Mutex) for the background).
+///
+/// Reading an initialized cell is lock-free and works in interrupt contexts; initializing it takes
+/// a mutex, and thus needs to happen in a thread.
+///
+/// [std::sync::OnceLock]: https://doc.rust-lang.org/std/sync/struct.OnceLock.html
+pub struct OnceCell<T> {
+    // Only ever locked while initializing
+    lock: Mutex<()>,
+    // Set (with Release ordering) only after the value was written
+    initialized: AtomicBool,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T> OnceCell<T> {
+    /// Create an empty cell
+    pub const fn new() -> Self {
+        OnceCell {
+            lock: Mutex::new(()),
+            initialized: AtomicBool::new(false),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// The cell's value, if it was initialized already
+    pub fn get(&self) -> Option<&T> {
+        if self.initialized.load(Ordering::Acquire) {
+            // unsafe: Once initialized is set, the value is written and never changes again
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Get the cell's value, initializing it with the closure if it is empty
+    ///
+    /// When called concurrently, the closure of exactly one caller is run, and all others block
+    /// until that initialization is complete.
+    ///
+    /// ## Panics
+    ///
+    /// This panics if initialization is attempted in an interrupt context. If the closure tries to
+    /// initialize the same cell, it deadlocks.
+    pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        let guard = self.lock.lock();
+        // Some other thread may have initialized it while we were waiting for the lock
+        if !self.initialized.load(Ordering::Acquire) {
+            let value = f();
+            // unsafe: Nobody reads the value before initialized is set, and nobody else writes
+            // while the lock is held.
+            unsafe { (*self.value.get()).write(value) };
+            self.initialized.store(true, Ordering::Release);
+        }
+        drop(guard);
+
+        self.get().expect("Was just initialized")
+    }
+
+    /// Access the cell's value through an exclusive reference, if it was initialized
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if *self.initialized.get_mut() {
+            // unsafe: It is initialized
+            Some(unsafe { self.value.get_mut().assume_init_mut() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Default for OnceCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OnceCell<T> {
+    fn drop(&mut self) {
+        if *self.initialized.get_mut() {
+            // unsafe: It is initialized, and will not be used again
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for OnceCell<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("OnceCell").field(value).finish(),
+            None => write!(f, "OnceCell(<uninit>)"),
+        }
+    }
+}
+
+// unsafe: The value is shared with all threads (requiring Sync) and may be initialized in any of
+// them (requiring Send).
+unsafe impl<T: Send + Sync> Sync for OnceCell<T> {}
+unsafe impl<T: Send> Send for OnceCell<T> {}
+
+/// A synchronization primitive to run a piece of code once
+///
+/// This is mainly for initialization that has side effects rather than producing a value; for
+/// the latter, [OnceCell] is more convenient. The same restrictions regarding interrupts and
+/// panics apply.
+#[derive(Default, Debug)]
+pub struct Once(OnceCell<()>);
+
+impl Once {
+    pub const fn new() -> Self {
+        Once(OnceCell::new())
+    }
+
+    /// Run the closure, unless this (or any other closure) has already been run on this Once
+    ///
+    /// When called concurrently, only one closure is run, and all callers return only after it
+    /// completed.
+    pub fn call_once(&self, f: impl FnOnce()) {
+        self.0.get_or_init(f);
+    }
+
+    /// Check whether a closure has been run on this Once
+    pub fn is_completed(&self) -> bool {
+        self.0.get().is_some()
+    }
+}
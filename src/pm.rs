@@ -0,0 +1,89 @@
+//! Access to RIOT's [power management](https://doc.riot-os.org/group__sys__pm__layer.html)
+//!
+//! Power modes are numbered from 0 (the deepest sleep mode, with the least power consumed and the
+//! fewest peripherals running) up to `PM_NUM_MODES - 1` (the lightest one). When the system is
+//! idle, it enters the deepest mode that is not blocked; blocking a mode thus also keeps the
+//! system out of all the modes numbered lower than it.
+//!
+//! Which modes exist and what is still running in them is specific to the CPU.
+
+/// Number of power modes the CPU supports
+#[cfg(riot_module_pm_layer)]
+pub const NUM_MODES: u8 = riot_sys::PM_NUM_MODES as _;
+
+/// Guard for a blocked power mode, obtained through [block]
+///
+/// While this exists, the system does not enter the blocked mode (or any deeper one); the block
+/// is released when the guard is dropped.
+#[cfg(riot_module_pm_layer)]
+#[doc(alias = "pm_unblock")]
+#[must_use = "The power mode is unblocked again when the guard is dropped"]
+#[derive(Debug)]
+pub struct BlockGuard {
+    mode: u8,
+}
+
+#[cfg(riot_module_pm_layer)]
+impl BlockGuard {
+    /// The mode that is blocked by this guard
+    pub fn mode(&self) -> u8 {
+        self.mode
+    }
+}
+
+#[cfg(riot_module_pm_layer)]
+impl Drop for BlockGuard {
+    fn drop(&mut self) {
+        // unsafe: C API; balanced with the pm_block in block() by construction
+        unsafe { riot_sys::pm_unblock(self.mode as _) };
+    }
+}
+
+/// Prevent the system from entering the given power mode (and any deeper one) while the returned
+/// guard is alive
+///
+/// Blocks are counted, so overlapping blocks of the same mode from different places are fine.
+///
+/// ## Panics
+///
+/// This panics if the mode is not below [NUM_MODES].
+#[cfg(riot_module_pm_layer)]
+#[doc(alias = "pm_block")]
+pub fn block(mode: u8) -> BlockGuard {
+    assert!(mode < NUM_MODES, "Power mode out of range");
+    // unsafe: C API, with the mode checked for validity
+    unsafe { riot_sys::pm_block(mode as _) };
+    BlockGuard { mode }
+}
+
+/// Switch the CPU into the deepest power mode that is not currently blocked
+///
+/// This is what the idle thread does when no other thread is running; calling it explicitly is
+/// rarely necessary.
+#[cfg(riot_module_pm_layer)]
+#[doc(alias = "pm_set_lowest")]
+pub fn set_lowest() {
+    // unsafe: C API without preconditions
+    unsafe { riot_sys::pm_set_lowest() };
+}
+
+/// Switch the device off
+///
+/// Depending on the CPU, this may also just put it into its deepest sleep mode, from which only a
+/// reset wakes it up.
+#[doc(alias = "pm_off")]
+pub fn off() -> ! {
+    // unsafe: C API without preconditions
+    unsafe { riot_sys::pm_off() };
+    // The C function is not declared NORETURN, but does not return.
+    loop {}
+}
+
+/// Reboot the device
+#[doc(alias = "pm_reboot")]
+pub fn reboot() -> ! {
+    // unsafe: C API without preconditions
+    unsafe { riot_sys::pm_reboot() };
+    // The C function is not declared NORETURN, but does not return.
+    loop {}
+}
@@ -0,0 +1,137 @@
+//! A byte queue built on RIOT's [ringbuffer](https://doc.riot-os.org/ringbuffer_8h.html)
+//!
+//! The ringbuffer is byte oriented; for more general queues, the `heapless` crate is often a
+//! better choice. This primarily exists for interoperability with C code that uses
+//! `ringbuffer_t`, and as a small-footprint queue that shares its code with the C components that
+//! use it.
+
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+
+/// A fixed-capacity queue of bytes
+///
+/// The buffer and the `ringbuffer_t` header that manages it are stored together. As the header's
+/// pointer to the buffer would make the type self-referential, that pointer is refreshed
+/// whenever the buffer is accessed, allowing the ringbuffer to be moved freely.
+///
+/// The queue is not [Sync]; to share it between threads, wrap it in a
+/// [Mutex](crate::mutex::Mutex).
+pub struct Ringbuffer<const N: usize> {
+    buffer: UnsafeCell<[u8; N]>,
+    header: UnsafeCell<riot_sys::ringbuffer_t>,
+}
+
+// unsafe: The only !Send part is the buffer pointer in the header, which is not relied on but
+// refreshed before every use.
+unsafe impl<const N: usize> Send for Ringbuffer<N> {}
+
+impl<const N: usize> Ringbuffer<N> {
+    /// Create an empty ringbuffer
+    #[doc(alias = "ringbuffer_init")]
+    pub fn new() -> Self {
+        let size: riot_sys::libc::c_uint = const {
+            assert!(
+                N <= riot_sys::libc::c_uint::MAX as usize,
+                "Buffer size exceeds what ringbuffer_t can express"
+            );
+            N as _
+        };
+
+        let mut header = MaybeUninit::uninit();
+        // unsafe: C function only sets fields; the buffer pointer is set at access time.
+        let header = unsafe {
+            riot_sys::inline::ringbuffer_init(
+                crate::inline_cast_mut(header.as_mut_ptr()),
+                core::ptr::null_mut(),
+                size,
+            );
+            header.assume_init()
+        };
+
+        Self {
+            buffer: UnsafeCell::new([0; N]),
+            header: UnsafeCell::new(header),
+        }
+    }
+
+    /// Pointer to the header, with its pointer to the buffer brought up to date
+    fn header(&self) -> *mut riot_sys::ringbuffer_t {
+        let header = self.header.get();
+        // unsafe: We're not Sync, and no reference into the header is ever held across calls.
+        unsafe { (*header).buf = self.buffer.get() as *mut _ };
+        header
+    }
+
+    /// Append a byte to the queue
+    ///
+    /// Unlike the underlying `ringbuffer_add_one` (which overwrites the oldest byte), this does
+    /// not alter the queue if it is full, and returns false instead.
+    #[doc(alias = "ringbuffer_add_one")]
+    pub fn push(&mut self, byte: u8) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        // unsafe: C API on a valid header. The return value only indicates whether a byte was
+        // dropped, which was ruled out above.
+        unsafe { riot_sys::ringbuffer_add_one(self.header(), byte as _) };
+        true
+    }
+
+    /// Remove the oldest byte from the queue and return it
+    #[doc(alias = "ringbuffer_get_one")]
+    pub fn pop(&mut self) -> Option<u8> {
+        // Checking explicitly rather than relying on the -1 return value, for on platforms with a
+        // signed char, a 0xff byte is returned as -1 as well.
+        if self.is_empty() {
+            return None;
+        }
+        // unsafe: C API on a valid header
+        Some(unsafe { riot_sys::ringbuffer_get_one(self.header()) } as u8)
+    }
+
+    /// Return the oldest byte of the queue without removing it
+    #[doc(alias = "ringbuffer_peek_one")]
+    pub fn peek(&self) -> Option<u8> {
+        // See pop() on why this is checked explicitly
+        if self.is_empty() {
+            return None;
+        }
+        // unsafe: C API on a valid header
+        Some(unsafe { riot_sys::ringbuffer_peek_one(self.header()) } as u8)
+    }
+
+    /// Number of bytes currently in the queue
+    pub fn len(&self) -> usize {
+        // unsafe: Plain field read on a valid header
+        unsafe { (*self.header()).avail as _ }
+    }
+
+    /// Maximum number of bytes the queue can hold
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    #[doc(alias = "ringbuffer_empty")]
+    pub fn is_empty(&self) -> bool {
+        // unsafe: C API on a valid header
+        unsafe { riot_sys::inline::ringbuffer_empty(crate::inline_cast(self.header())) != 0 }
+    }
+
+    #[doc(alias = "ringbuffer_full")]
+    pub fn is_full(&self) -> bool {
+        // unsafe: C API on a valid header
+        unsafe { riot_sys::inline::ringbuffer_full(crate::inline_cast(self.header())) != 0 }
+    }
+}
+
+impl<const N: usize> Default for Ringbuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for Ringbuffer<N> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(fmt, "Ringbuffer {{ {} of {} bytes used }}", self.len(), N)
+    }
+}
@@ -0,0 +1,189 @@
+//! Access to the [real-time clock](https://doc.riot-os.org/group__drivers__periph__rtc.html)
+//!
+//! Times are exchanged as [DateTime] values; the RTC has no concept of time zones, so it is up to
+//! the application to decide (and keep consistent) whether it runs in UTC or local time.
+
+use crate::error::{NegativeErrorExt, NumericError};
+
+/// A calendar date and time of day, as kept by the RTC
+///
+/// All fields are in their natural human-readable ranges (eg. `month` is 1 to 12), unlike in the
+/// `struct tm` this is converted from and to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateTime {
+    pub year: u16,
+    /// Month of the year, 1 to 12
+    pub month: u8,
+    /// Day of the month, starting at 1
+    pub day: u8,
+    /// Hour of the day, 0 to 23
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl DateTime {
+    fn is_leap_year(year: u16) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Check that all fields are in their ranges (including the day of the month matching the
+    /// month)
+    ///
+    /// Leap seconds are not supported.
+    pub fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month)
+            && (1..=Self::days_in_month(self.year, self.month)).contains(&self.day)
+            && self.year >= 1900
+            && self.hour < 24
+            && self.minute < 60
+            && self.second < 60
+    }
+
+    pub(crate) fn to_tm(&self) -> Result<riot_sys::tm, NumericError> {
+        if !self.is_valid() {
+            return Err(crate::error::EINVAL);
+        }
+        let mut tm = riot_sys::tm::default();
+        tm.tm_year = (self.year - 1900) as _;
+        tm.tm_mon = (self.month - 1) as _;
+        tm.tm_mday = self.day as _;
+        tm.tm_hour = self.hour as _;
+        tm.tm_min = self.minute as _;
+        tm.tm_sec = self.second as _;
+        // The day of the week and the day of the year are not evaluated by the RTC drivers, but
+        // let's not leave them inconsistent
+        // unsafe: C function on a valid struct
+        unsafe { riot_sys::rtc_tm_normalize(&mut tm) };
+        Ok(tm)
+    }
+
+    pub(crate) fn from_tm(tm: &riot_sys::tm) -> Result<Self, NumericError> {
+        let bad = || NumericError::from_constant(riot_sys::EBADMSG as _);
+        let result = DateTime {
+            year: u16::try_from(tm.tm_year)
+                .ok()
+                .and_then(|y| y.checked_add(1900))
+                .ok_or_else(bad)?,
+            month: u8::try_from(tm.tm_mon)
+                .ok()
+                .and_then(|m| m.checked_add(1))
+                .ok_or_else(bad)?,
+            day: tm.tm_mday.try_into().map_err(|_| bad())?,
+            hour: tm.tm_hour.try_into().map_err(|_| bad())?,
+            minute: tm.tm_min.try_into().map_err(|_| bad())?,
+            second: tm.tm_sec.try_into().map_err(|_| bad())?,
+        };
+        match result.is_valid() {
+            true => Ok(result),
+            false => Err(bad()),
+        }
+    }
+}
+
+/// Read the current time from the RTC
+#[doc(alias = "rtc_get_time")]
+pub fn get_time() -> Result<DateTime, NumericError> {
+    let mut tm = riot_sys::tm::default();
+    // unsafe: C function on a valid struct
+    unsafe { riot_sys::rtc_get_time(&mut tm) }.negative_to_error()?;
+    DateTime::from_tm(&tm)
+}
+
+/// Set the RTC to the given time
+///
+/// This fails with `EINVAL` if the time is not [valid](DateTime::is_valid).
+#[doc(alias = "rtc_set_time")]
+pub fn set_time(time: &DateTime) -> Result<(), NumericError> {
+    let mut tm = time.to_tm()?;
+    // unsafe: C function on a valid struct
+    unsafe { riot_sys::rtc_set_time(&mut tm) }
+        .negative_to_error()
+        .map(|_| ())
+}
+
+/// An RTC alarm that runs a callback at a given time
+///
+/// The callback is executed in the RTC's interrupt context; consequently, it needs to be [Send],
+/// and must not block.
+///
+/// As the RTC keeps a reference to the callback while the alarm is set, the alarm is set through
+/// a pinned reference: `let alarm = pin!(Alarm::new(callback)); alarm.as_mut().set(&time)?;`.
+/// When the alarm is dropped, it is cleared.
+///
+/// RTCs support only a single alarm. Setting an alarm replaces any alarm that was set before,
+/// and dropping (or clearing) any alarm clears whichever alarm is currently set.
+#[pin_project::pin_project(PinnedDrop)]
+pub struct Alarm<F: FnMut() + Send> {
+    callback: F,
+    #[pin]
+    _pin: core::marker::PhantomPinned,
+}
+
+impl<F: FnMut() + Send> Alarm<F> {
+    pub fn new(callback: F) -> Self {
+        Alarm {
+            callback,
+            _pin: core::marker::PhantomPinned,
+        }
+    }
+
+    extern "C" fn call(arg: *mut riot_sys::libc::c_void) {
+        // unsafe: Set from the pinned callback, which outlives the set alarm. Reference
+        // construction: The thread does not access the callback while the alarm is set.
+        let callback = unsafe { &mut *(arg as *mut F) };
+        callback();
+    }
+
+    /// Set the alarm to run the callback at the given time.
+    ///
+    /// This fails with `EINVAL` if the time is not [valid](DateTime::is_valid).
+    #[doc(alias = "rtc_set_alarm")]
+    pub fn set(self: core::pin::Pin<&mut Self>, time: &DateTime) -> Result<(), NumericError> {
+        let mut tm = time.to_tm()?;
+        // Not accessing the callback while the alarm may fire
+        clear_alarm();
+        let callback = self.project().callback as *mut F;
+        // unsafe: C function with a callback argument that stays valid until the alarm is
+        // cleared in the drop
+        unsafe { riot_sys::rtc_set_alarm(&mut tm, Some(Self::call), callback as *mut _) }
+            .negative_to_error()
+            .map(|_| ())
+    }
+
+    /// Clear the alarm (and, as there is only one, any other alarm as well)
+    #[doc(alias = "rtc_clear_alarm")]
+    pub fn clear(self: core::pin::Pin<&mut Self>) {
+        clear_alarm();
+    }
+}
+
+#[pin_project::pinned_drop]
+impl<F: FnMut() + Send> PinnedDrop for Alarm<F> {
+    fn drop(self: core::pin::Pin<&mut Self>) {
+        self.clear();
+    }
+}
+
+fn clear_alarm() {
+    // unsafe: C function without preconditions
+    unsafe { riot_sys::rtc_clear_alarm() };
+}
+
+/// Read the time the RTC alarm is set to
+#[doc(alias = "rtc_get_alarm")]
+pub fn get_alarm() -> Result<DateTime, NumericError> {
+    let mut tm = riot_sys::tm::default();
+    // unsafe: C function on a valid struct
+    unsafe { riot_sys::rtc_get_alarm(&mut tm) }.negative_to_error()?;
+    DateTime::from_tm(&tm)
+}
@@ -1,8 +1,56 @@
 /// Wrappers for elements of the Sock API
 
+#[cfg(riot_module_sock_udp)]
+use core::pin::Pin;
+
+#[cfg(riot_module_sock_udp)]
+use pin_project::{pin_project, pinned_drop};
+
+#[cfg(riot_module_sock_udp)]
+use crate::error::{NegativeErrorExt, NumericError};
+
+/// A UDP endpoint, consisting of an address, a port and optionally a network interface
+#[doc(alias = "SockEndpoint")]
 pub struct UdpEp(pub(crate) riot_sys::sock_udp_ep_t);
 
 impl UdpEp {
+    /// Create an IPv6 endpoint
+    ///
+    /// The interface is only relevant for link-local addresses; if none is given, any interface
+    /// is used.
+    pub fn new_ipv6(addr: &core::net::Ipv6Addr, port: u16, netif: Option<u16>) -> Self {
+        // Constructing via default avoids using the volatile names of the union types
+        let mut ep: riot_sys::sock_udp_ep_t = Default::default();
+        ep.family = riot_sys::AF_INET6 as _;
+        ep.addr.ipv6 = addr.octets();
+        ep.port = port;
+        // 0 is SOCK_ADDR_ANY_NETIF
+        ep.netif = netif.unwrap_or(0);
+        UdpEp(ep)
+    }
+
+    /// The IPv6 address of the endpoint, if it is an IPv6 endpoint
+    pub fn ipv6_addr(&self) -> Option<core::net::Ipv6Addr> {
+        match self.0.family as _ {
+            // unsafe: Access to C union whose type was just checked
+            riot_sys::AF_INET6 => Some(unsafe { self.0.addr.ipv6 }.into()),
+            _ => None,
+        }
+    }
+
+    pub fn port(&self) -> u16 {
+        self.0.port
+    }
+
+    /// The network interface of the endpoint, if one is set
+    pub fn netif(&self) -> Option<u16> {
+        // 0 is SOCK_ADDR_ANY_NETIF
+        match self.0.netif {
+            0 => None,
+            n => Some(n),
+        }
+    }
+
     #[doc(alias = "SOCK_IPV6_EP_ANY")]
     pub fn ipv6_any() -> Self {
         // unsafe: Side effect free C macro
@@ -64,6 +112,153 @@ impl Into<riot_sys::sock_udp_ep_t> for UdpEp {
     }
 }
 
+/// A place where a [UdpSock] can be stored
+///
+/// Sockets are managed by RIOT in linked lists and message boxes, so they can not move while they
+/// are open; any pinned slot (eg. from `pin!`) will do.
+///
+/// ## Invariants
+///
+/// If the socket is present, it has been created and not yet closed.
+#[cfg(riot_module_sock_udp)]
+#[derive(Default)]
+#[pin_project(PinnedDrop)]
+pub struct UdpSockSlot(
+    #[pin] Option<riot_sys::sock_udp_t>,
+    core::marker::PhantomPinned,
+);
+
+#[cfg(riot_module_sock_udp)]
+impl UdpSockSlot {
+    /// Cleanly replace any Some with None.
+    fn close(self: Pin<&mut Self>) {
+        // unsafe: The socket is closed before it is dropped
+        let sock = unsafe { Pin::into_inner_unchecked(self.project().0) };
+        if let Some(sock) = sock.as_mut() {
+            unsafe { riot_sys::sock_udp_close(sock) };
+        }
+        *sock = None;
+    }
+}
+
+#[cfg(riot_module_sock_udp)]
+#[pinned_drop]
+impl PinnedDrop for UdpSockSlot {
+    fn drop(self: Pin<&mut Self>) {
+        self.close();
+    }
+}
+
+/// A blocking UDP socket
+///
+/// This is a thin wrapper around a `sock_udp_t` for applications that do not need the
+/// abstractions of embedded-nal.
+///
+/// ## Invariants
+///
+/// While this is active, the inner [UdpSockSlot] always contains Some.
+#[cfg(riot_module_sock_udp)]
+pub struct UdpSock<'a>(Pin<&'a mut UdpSockSlot>);
+
+#[cfg(riot_module_sock_udp)]
+impl<'a> UdpSock<'a> {
+    /// Create a socket bound to the local endpoint
+    ///
+    /// As the socket can not move while it is open, its place needs to be pre-pinned; see
+    /// [UdpSockSlot].
+    #[doc(alias = "sock_udp_create")]
+    pub fn bind(local: &UdpEp, mut slot: Pin<&'a mut UdpSockSlot>) -> Result<Self, NumericError> {
+        slot.as_mut().close();
+
+        // unsafe: The socket is placed in its final position before it is created
+        let sock = unsafe { Pin::into_inner_unchecked(slot.as_mut().project().0) }
+            .insert(Default::default());
+        if let Err(e) =
+            (unsafe { riot_sys::sock_udp_create(sock, local.as_ref(), core::ptr::null(), 0) })
+                .negative_to_error()
+        {
+            // unsafe: It was not created, so it must not be closed either.
+            *unsafe { Pin::into_inner_unchecked(slot.as_mut().project().0) } = None;
+            return Err(e);
+        }
+
+        Ok(UdpSock(slot))
+    }
+
+    fn sock(&mut self) -> *mut riot_sys::sock_udp_t {
+        // unsafe: Only used as a pointer that is passed to C functions that don't move it
+        let sock = unsafe { Pin::into_inner_unchecked(self.0.as_mut().project().0) };
+        sock.as_mut().expect("Present per invariants") as *mut _
+    }
+
+    /// Send a datagram to the remote endpoint
+    #[doc(alias = "sock_udp_send")]
+    pub fn send_to(&mut self, data: &[u8], remote: &UdpEp) -> Result<usize, NumericError> {
+        (unsafe {
+            riot_sys::sock_udp_send(
+                crate::inline_cast_mut(self.sock()),
+                data.as_ptr() as _,
+                data.len() as _,
+                remote.as_ref(),
+            )
+        })
+        .negative_to_error()
+        .map(|n| n as _)
+    }
+
+    /// Receive a datagram into the buffer, and return its length and the sender's endpoint
+    ///
+    /// If no datagram arrives within the timeout, this produces an `ETIMEDOUT` error, which in
+    /// RIOT is implemented using ztimer. Without timeout, this blocks until a datagram arrives. A
+    /// zero timeout returns `EAGAIN` if no datagram is pending.
+    #[doc(alias = "sock_udp_recv")]
+    pub fn recv_from(
+        &mut self,
+        buf: &mut [u8],
+        timeout: Option<core::time::Duration>,
+    ) -> Result<(usize, UdpEp), NumericError> {
+        let timeout = match timeout {
+            // Saturating below u32::MAX, which is SOCK_NO_TIMEOUT
+            Some(t) => t.as_micros().min(u32::MAX as u128 - 1) as u32,
+            None => u32::MAX,
+        };
+
+        let mut remote = core::mem::MaybeUninit::uninit();
+        let read = (unsafe {
+            riot_sys::sock_udp_recv(
+                crate::inline_cast_mut(self.sock()),
+                buf.as_mut_ptr() as _,
+                buf.len() as _,
+                timeout,
+                crate::inline_cast_mut(remote.as_mut_ptr() as *mut _),
+            )
+        })
+        .negative_to_error()?;
+
+        // unsafe: Set by C function on success (and we returned early otherwise)
+        let remote = UdpEp(unsafe { remote.assume_init() });
+
+        Ok((read as _, remote))
+    }
+
+    /// The local endpoint the socket is bound to
+    #[doc(alias = "sock_udp_get_local")]
+    pub fn local(&mut self) -> Result<UdpEp, NumericError> {
+        let mut local = core::mem::MaybeUninit::uninit();
+        (unsafe { riot_sys::sock_udp_get_local(self.sock(), local.as_mut_ptr()) })
+            .negative_to_error()?;
+        // unsafe: Set by C function on success
+        Ok(UdpEp(unsafe { local.assume_init() }))
+    }
+}
+
+#[cfg(riot_module_sock_udp)]
+impl Drop for UdpSock<'_> {
+    fn drop(&mut self) {
+        self.0.as_mut().close();
+    }
+}
+
 // Gated to its users to avoid dead code warnings
 #[cfg(any(feature = "with_embedded_nal", feature = "with_embedded_nal_async"))]
 macro_rules! implementation_no_std_net {
@@ -208,12 +208,12 @@ impl<'a, const UDPCOUNT: usize> embedded_nal::UdpClientStack for StackAccessor<'
         })
         .negative_to_error()
         .map(|e| e as usize)
-        .map_err(|e| e.again_is_wouldblock());
+        .map_err(|e| e.again_is_wouldblock())?;
 
-        // unsafe: Set by C function
+        // unsafe: Set by C function on success (and we returned early otherwise)
         let remote = UdpEp(unsafe { remote.assume_init() });
 
-        Ok((read?, remote.into()))
+        Ok((read, remote.into()))
     }
 
     fn close(&mut self, mut socket: Self::UdpSocket) -> Result<(), Self::Error> {
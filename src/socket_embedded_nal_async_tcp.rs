@@ -0,0 +1,192 @@
+//! An implementation of the [embedded_nal_async] TCP client trait based on RIOT sockets
+//!
+//! No non-blocking connect is available in RIOT's sock API, so establishing a connection blocks
+//! the executor until the handshake is complete (or failed). Reading is fully asynchronous, using
+//! sock's async callbacks to wake the task. Writing completes when the data was accepted by the
+//! socket, which may block while the send buffer is full.
+
+use crate::async_helpers::{RiotStyleFuture, RiotStylePollStruct};
+use crate::error::{NegativeErrorExt, NumericError, ENOSPC};
+use crate::socket::UdpEp;
+use core::cell::Cell;
+use core::mem::MaybeUninit;
+use riot_sys::sock_tcp_t;
+
+use embedded_nal_async_0_7 as embedded_nal_async;
+
+/// The network stack, used to get an implementation of [embedded_nal_async::TcpConnect]
+///
+/// As RIOT sockets can not move while they are in use, the sockets are taken from a factory
+/// function that produces static memory for them, just as with the
+/// [UDP stack](crate::socket_embedded_nal_async_udp::UdpStack).
+///
+/// Memory of a socket whose connection attempt failed is kept by the stack, and used for the next
+/// connection before the factory is asked again.
+pub struct TcpStack {
+    static_socket_factory: fn() -> Option<&'static mut MaybeUninit<sock_tcp_t>>,
+    spare: Cell<Option<&'static mut MaybeUninit<sock_tcp_t>>>,
+}
+
+impl TcpStack {
+    pub fn new(factory: fn() -> Option<&'static mut MaybeUninit<sock_tcp_t>>) -> Self {
+        Self {
+            static_socket_factory: factory,
+            spare: Cell::new(None),
+        }
+    }
+}
+
+impl core::fmt::Debug for TcpStack {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TcpStack")
+            .field("static_socket_factory", &self.static_socket_factory)
+            .finish_non_exhaustive()
+    }
+}
+
+impl embedded_nal_async::TcpConnect for TcpStack {
+    type Error = NumericError;
+    type Connection<'a> = TcpConnection;
+
+    /// Connect to the remote
+    ///
+    /// **This blocks**: RIOT's sock API has no non-blocking connect, so the executor does not run
+    /// any other task until the handshake completed or failed (which, if the remote does not
+    /// respond, takes as long as the TCP implementation's connection timeout).
+    #[doc(alias = "sock_tcp_connect")]
+    async fn connect<'a>(
+        &'a self,
+        remote: embedded_nal_async::SocketAddr,
+    ) -> Result<Self::Connection<'a>, Self::Error> {
+        let socket = match self.spare.take() {
+            Some(socket) => socket,
+            None => (self.static_socket_factory)().ok_or(ENOSPC)?,
+        };
+        let remote: UdpEp = remote.into();
+
+        // unsafe: C API; the socket memory stays valid and in place for the lifetime of the
+        // connection.
+        //
+        // Reusing UdpEp because TcpEp is probably (FIXME) all the same.
+        let result = unsafe {
+            riot_sys::sock_tcp_connect(socket.as_mut_ptr(), remote.as_ref(), 0, 0)
+                .negative_to_error()
+        };
+        if let Err(e) = result {
+            // The socket is not in use after a failed connect, and is initialized anew when
+            // reused.
+            self.spare.set(Some(socket));
+            return Err(e);
+        }
+
+        Ok(TcpConnection {
+            // unsafe: Initialized by the successful connect
+            socket: unsafe { socket.assume_init_mut() },
+        })
+    }
+}
+
+/// An established connection created through [TcpStack]
+///
+/// The connection is closed when this is dropped.
+#[derive(Debug)]
+pub struct TcpConnection {
+    socket: &'static mut sock_tcp_t,
+}
+
+impl Drop for TcpConnection {
+    fn drop(&mut self) {
+        unsafe { riot_sys::sock_tcp_disconnect(self.socket) };
+    }
+}
+
+impl embedded_io_async::ErrorType for TcpConnection {
+    type Error = NumericError;
+}
+
+impl embedded_io_async::Read for TcpConnection {
+    #[doc(alias = "sock_tcp_read")]
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        RiotStylePollStruct::new(ReadArgs {
+            sock: self.socket,
+            buffer: buf,
+        })
+        .await
+    }
+}
+
+impl embedded_io_async::Write for TcpConnection {
+    #[doc(alias = "sock_tcp_write")]
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        (unsafe {
+            riot_sys::sock_tcp_write(
+                self.socket,
+                buf.as_ptr() as _,
+                buf.len().try_into().unwrap_or(u32::MAX) as _,
+            )
+        })
+        .negative_to_error()
+        .map(|n| n as _)
+    }
+}
+
+struct ReadArgs<'a> {
+    sock: &'a mut sock_tcp_t,
+    buffer: &'a mut [u8],
+}
+
+impl RiotStyleFuture for ReadArgs<'_> {
+    type Output = Result<usize, NumericError>;
+
+    fn poll(&mut self, arg: *mut riot_sys::libc::c_void) -> core::task::Poll<Self::Output> {
+        let sock: &mut sock_tcp_t = self.sock;
+
+        // The callback is set before trying, so that data arriving between an unsuccessful
+        // attempt and the callback registration is not missed.
+        //
+        // When setting all this up, we got a &mut self, so we can be sure that there is no other
+        // task simultaneously trying to receive on this (which would overwrite the callback).
+        unsafe { riot_sys::sock_tcp_set_cb(sock, Some(Self::callback), arg) };
+
+        match (unsafe {
+            riot_sys::sock_tcp_read(
+                sock,
+                self.buffer.as_mut_ptr() as _,
+                self.buffer.len().try_into().unwrap_or(u32::MAX) as _,
+                // Return immediately
+                0,
+            )
+        })
+        .negative_to_error()
+        {
+            Err(crate::error::EAGAIN) => core::task::Poll::Pending,
+            result => {
+                unsafe { riot_sys::sock_tcp_set_cb(sock, None, core::ptr::null_mut()) };
+                core::task::Poll::Ready(result.map(|n| n as _))
+            }
+        }
+    }
+}
+
+impl ReadArgs<'_> {
+    unsafe extern "C" fn callback(
+        _sock: *mut sock_tcp_t,
+        flags: riot_sys::sock_async_flags_t,
+        arg: *mut riot_sys::libc::c_void,
+    ) {
+        // Both received data and a closed connection make the read complete
+        if flags & (riot_sys::inline::SOCK_ASYNC_MSG_RECV | riot_sys::inline::SOCK_ASYNC_CONN_FIN)
+            == 0
+        {
+            return;
+        }
+        RiotStylePollStruct::<Self>::callback(arg);
+    }
+}
+
+impl Drop for ReadArgs<'_> {
+    fn drop(&mut self) {
+        let sock: &mut sock_tcp_t = self.sock;
+        unsafe { riot_sys::sock_tcp_set_cb(sock, None, core::ptr::null_mut()) };
+    }
+}
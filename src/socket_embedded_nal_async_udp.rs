@@ -53,13 +53,6 @@ fn get_local(socket: &mut sock_udp_t) -> Result<UdpEp, NumericError> {
     .into())
 }
 
-impl embedded_io_async::Error for NumericError {
-    fn kind(&self) -> embedded_io_async::ErrorKind {
-        // FIXME there are some that do make sense here
-        embedded_io_async::ErrorKind::Other
-    }
-}
-
 #[derive(Debug)]
 pub struct ConnectedUdpSocket {
     socket: &'static mut sock_udp_t,
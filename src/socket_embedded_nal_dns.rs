@@ -0,0 +1,75 @@
+//! An implementation of the [embedded_nal] DNS trait based on RIOT's
+//! [sock_dns](https://doc.riot-os.org/group__net__sock__dns.html) client
+
+use crate::error::{NegativeErrorExt, NumericError};
+
+use embedded_nal::{AddrType, IpAddr};
+
+/// Longest host name accepted in a query; longer names are not valid in DNS, and are rejected
+/// with `EINVAL`
+const MAX_NAME_LEN: usize = 253;
+
+/// A DNS resolver using the system's configured DNS server (`sock_dns_server`)
+///
+/// Queries are blocking; resolution either succeeds or fails with an error, and never produces
+/// `WouldBlock`. Errors are passed on from RIOT, and commonly are `ETIMEDOUT` when the server did
+/// not respond, or `EHOSTUNREACH` when the name could not be resolved.
+#[derive(Debug, Default)]
+pub struct Resolver {
+    _private: (),
+}
+
+impl Resolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl embedded_nal::Dns for Resolver {
+    type Error = NumericError;
+
+    #[doc(alias = "sock_dns_query")]
+    fn get_host_by_name(
+        &mut self,
+        hostname: &str,
+        addr_type: AddrType,
+    ) -> nb::Result<IpAddr, Self::Error> {
+        let mut name: heapless::String<{ MAX_NAME_LEN + 1 }> = heapless::String::new();
+        name.push_str(hostname).map_err(|_| crate::error::EINVAL)?;
+        name.push_str("\0").map_err(|_| crate::error::EINVAL)?;
+        let name = core::ffi::CStr::from_bytes_with_nul(name.as_bytes())
+            .map_err(|_| crate::error::EINVAL)?;
+
+        let family = match addr_type {
+            AddrType::IPv4 => riot_sys::AF_INET,
+            AddrType::IPv6 => riot_sys::AF_INET6,
+            AddrType::Either => riot_sys::AF_UNSPEC,
+        };
+
+        // Large enough for either address type
+        let mut addr = [0u8; 16];
+        // unsafe: C API with valid pointers; the output buffer is large enough for any family
+        let len = (unsafe {
+            riot_sys::sock_dns_query(name.as_ptr() as _, addr.as_mut_ptr() as _, family as _)
+        })
+        .negative_to_error()?;
+
+        // The length indicates which family the server answered with
+        match len {
+            4 => {
+                let v4: [u8; 4] = addr[..4].try_into().unwrap();
+                Ok(IpAddr::V4(v4.into()))
+            }
+            16 => Ok(IpAddr::V6(addr.into())),
+            _ => Err(NumericError::from_constant(riot_sys::EBADMSG as _).into()),
+        }
+    }
+
+    /// Reverse lookups are not supported by RIOT's DNS client; this always fails with `ENOTSUP`.
+    fn get_host_by_address(
+        &mut self,
+        _addr: IpAddr,
+    ) -> nb::Result<embedded_nal::heapless::String<256>, Self::Error> {
+        Err(NumericError::from_constant(riot_sys::ENOTSUP as _).into())
+    }
+}
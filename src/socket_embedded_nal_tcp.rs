@@ -5,6 +5,9 @@
 //! vastly different workarounds (and because it was implemented when embedded-nal had already
 //! switched over to &mut stack).
 //!
+//! Two stacks are provided: A [ListenStack] for servers that accept incoming connections on a
+//! single port, and a [ConnectStack] for clients that establish outgoing connections.
+//!
 //! ## Warning
 //!
 //! The implementation of TcpExactStack is highly naïve, and may panic already with well-behaved
@@ -12,6 +15,7 @@
 
 use core::convert::TryInto;
 use core::marker::PhantomData;
+use core::marker::PhantomPinned;
 use core::mem::MaybeUninit;
 use core::pin::Pin;
 
@@ -287,3 +291,169 @@ impl<'a, const QUEUELEN: usize> embedded_nal_tcpextensions::TcpExactStack
         Ok(())
     }
 }
+
+/// A view on the RIOT socket stack that is prepared for up to CONNCOUNT simultaneous outgoing
+/// connections.
+///
+/// To use it as an implementation of TcpClientStack, it needs to be pinned, eg. by
+/// `pin_utils::pin_mut!(stack)`, and later passed as mutable reference to the pinned item.
+///
+/// Unlike the [ListenStack], this keeps track of which of its connections are in use; thus,
+/// sockets are freed for reuse when closed, and any connection that is still open when the stack
+/// is dropped is disconnected.
+pub struct ConnectStack<const CONNCOUNT: usize> {
+    connections: [riot_sys::sock_tcp_t; CONNCOUNT],
+    /// Indicates for each connection whether it has been handed out through a [ConnectSocket]
+    used: [bool; CONNCOUNT],
+    /// Indicates for each connection whether it is connected (and thus needs disconnecting)
+    connected: [bool; CONNCOUNT],
+    // because by passing connections to the socket API, we promise not to move them any more
+    _unpin: PhantomPinned,
+}
+
+impl<const CONNCOUNT: usize> Default for ConnectStack<CONNCOUNT> {
+    fn default() -> Self {
+        ConnectStack {
+            // As for the ListenStack, this is usually one-time cost.
+            connections: [Default::default(); CONNCOUNT],
+            used: [false; CONNCOUNT],
+            connected: [false; CONNCOUNT],
+            _unpin: Default::default(),
+        }
+    }
+}
+
+impl<const CONNCOUNT: usize> ConnectStack<CONNCOUNT> {
+    /// Access the connection at a given index
+    ///
+    /// The index is trusted to come from a [ConnectSocket] of this stack.
+    fn connection(self: &mut Pin<&mut Self>, index: u8) -> *mut riot_sys::sock_tcp_t {
+        // unsafe: Only producing a pointer; nothing is moved.
+        unsafe { &mut self.as_mut().get_unchecked_mut().connections[usize::from(index)] }
+    }
+
+    /// Access the bookkeeping fields (which may be moved around freely)
+    fn flags(self: &mut Pin<&mut Self>) -> (&mut [bool; CONNCOUNT], &mut [bool; CONNCOUNT]) {
+        // unsafe: We never promise not to move *those*.
+        let s = unsafe { self.as_mut().get_unchecked_mut() };
+        (&mut s.used, &mut s.connected)
+    }
+}
+
+impl<const CONNCOUNT: usize> Drop for ConnectStack<CONNCOUNT> {
+    fn drop(&mut self) {
+        // All ConnectSockets are lifetime bound to the stack, so they can not be used any more.
+        for (sock, connected) in self.connections.iter_mut().zip(self.connected.iter()) {
+            if *connected {
+                unsafe { riot_sys::sock_tcp_disconnect(sock) };
+            }
+        }
+    }
+}
+
+/// Socket for a [ConnectStack].
+///
+/// The lifetime is used as branding to ensure sockets are always used with their respective
+/// stacks.
+#[derive(Debug)]
+pub struct ConnectSocket<'a> {
+    // Assuming no more than 256 connections, as the ListenStack does.
+    index: u8,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, const CONNCOUNT: usize> TcpClientStack for Pin<&'a mut ConnectStack<CONNCOUNT>> {
+    type TcpSocket = ConnectSocket<'a>;
+    type Error = NumericError;
+
+    fn socket(&mut self) -> Result<Self::TcpSocket, Self::Error> {
+        let (used, _) = self.flags();
+        let index = used.iter().position(|u| !u).ok_or(crate::error::ENOMEM)?;
+        used[index] = true;
+        Ok(ConnectSocket {
+            index: index.try_into().expect("Excessive pool"),
+            _phantom: PhantomData,
+        })
+    }
+
+    fn connect(
+        &mut self,
+        sock: &mut Self::TcpSocket,
+        addr: SocketAddr,
+    ) -> Result<(), nb::Error<Self::Error>> {
+        if self.connected[usize::from(sock.index)] {
+            return Err(nb::Error::Other(NumericError::from_constant(
+                riot_sys::EISCONN as _,
+            )));
+        }
+
+        let remote: crate::socket::UdpEp = addr.into();
+
+        // On GNRC TCP, this blocks until the connection is established (or failed)
+        unsafe {
+            riot_sys::sock_tcp_connect(
+                self.connection(sock.index),
+                remote.as_ref(),
+                0, // pick an ephemeral local port
+                0,
+            )
+        }
+        .negative_to_error()
+        .map_err(|e| e.again_is_wouldblock())?;
+
+        self.flags().1[usize::from(sock.index)] = true;
+
+        Ok(())
+    }
+
+    fn is_connected(&mut self, sock: &Self::TcpSocket) -> Result<bool, Self::Error> {
+        Ok(self.connected[usize::from(sock.index)])
+    }
+
+    fn send(
+        &mut self,
+        sock: &mut Self::TcpSocket,
+        buf: &[u8],
+    ) -> Result<usize, nb::Error<Self::Error>> {
+        // Partial writes are passed on as the embedded-nal trait requests
+        unsafe {
+            riot_sys::sock_tcp_write(
+                self.connection(sock.index),
+                buf.as_ptr() as *const _,
+                buf.len().try_into().unwrap_or(u32::MAX),
+            )
+        }
+        .negative_to_error()
+        .map_err(|e| e.again_is_wouldblock())
+        .map(|n| n as _)
+    }
+
+    fn receive(
+        &mut self,
+        sock: &mut Self::TcpSocket,
+        buf: &mut [u8],
+    ) -> Result<usize, nb::Error<Self::Error>> {
+        unsafe {
+            riot_sys::sock_tcp_read(
+                self.connection(sock.index),
+                buf.as_ptr() as *mut _,
+                buf.len().try_into().unwrap_or(u32::MAX),
+                0,
+            )
+        }
+        .negative_to_error()
+        .map_err(|e| e.again_is_wouldblock())
+        .map(|n| n as _)
+    }
+
+    fn close(&mut self, sock: Self::TcpSocket) -> Result<(), Self::Error> {
+        let index = usize::from(sock.index);
+        if self.connected[index] {
+            unsafe { riot_sys::sock_tcp_disconnect(self.connection(sock.index)) };
+        }
+        let (used, connected) = self.flags();
+        used[index] = false;
+        connected[index] = false;
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+//! Implementation of [embedded_hal_async::spi::SpiDevice] on an [SPIDevice]
+
+use core::convert::Infallible;
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::spi::{ErrorType, Operation, SpiDevice};
+use riot_sys::{spi_clk_t, spi_cs_t, spi_mode_t, spi_transfer_bytes};
+
+use super::{AcquiredSPI, SPIDevice};
+
+/// An SPI bus with the chip select line and settings of one device on it, usable by async
+/// drivers through [embedded_hal_async::spi::SpiDevice]
+///
+/// The chip select line is driven by RIOT as part of the transfers; it can be any GPIO pin (see
+/// `SPI_HWCS` for hardware chip select lines where the peripheral has them).
+///
+/// ## Caveats
+///
+/// RIOT's SPI transfers are blocking, so the future returned by the transaction only yields
+/// during [Operation::DelayNs]. The bus stays acquired during such a delay, so other tasks that
+/// access the same bus from the same thread wait for the transaction to complete. The delay runs
+/// on the microsecond clock, and may thus be longer than requested (see
+/// [crate::ztimer::Delay]).
+pub struct AsyncSpiDevice {
+    bus: SPIDevice,
+    cs: spi_cs_t,
+    mode: spi_mode_t,
+    clk: spi_clk_t,
+}
+
+impl AsyncSpiDevice {
+    pub fn new(bus: SPIDevice, cs: spi_cs_t, mode: spi_mode_t, clk: spi_clk_t) -> Self {
+        AsyncSpiDevice { bus, cs, mode, clk }
+    }
+
+    /// Give back the bus, eg. to use it with a different device
+    pub fn into_inner(self) -> SPIDevice {
+        self.bus
+    }
+}
+
+impl ErrorType for AsyncSpiDevice {
+    type Error = Infallible;
+}
+
+impl SpiDevice for AsyncSpiDevice {
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), Infallible> {
+        let mut spi = Deselecting {
+            spi: self.bus.acquire(self.cs, self.mode, self.clk),
+            selected: false,
+        };
+        let count = operations.len();
+        for (i, operation) in operations.iter_mut().enumerate() {
+            let last = i + 1 == count;
+            match operation {
+                Operation::Read(read) => spi.transfer(None, Some(&mut read[..]), last),
+                Operation::Write(write) => spi.transfer(Some(&write[..]), None, last),
+                Operation::Transfer(read, write) => {
+                    // Shorter buffers are padded (when writing) or ignored (when reading), as
+                    // the trait demands.
+                    let common = read.len().min(write.len());
+                    let (read, read_rest) = read.split_at_mut(common);
+                    let (write, write_rest) = write.split_at(common);
+                    let tail = !read_rest.is_empty() || !write_rest.is_empty();
+                    spi.transfer(Some(write), Some(read), last && !tail);
+                    if !read_rest.is_empty() {
+                        spi.transfer(None, Some(read_rest), last);
+                    }
+                    if !write_rest.is_empty() {
+                        spi.transfer(Some(write_rest), None, last);
+                    }
+                }
+                Operation::TransferInPlace(words) => spi.transfer_in_place(words, last),
+                Operation::DelayNs(ns) => crate::ztimer::Delay.delay_ns(*ns).await,
+            }
+        }
+        // Any chip select left asserted (eg. when the transaction ended in a delay) is released
+        // when dropping spi.
+        Ok(())
+    }
+}
+
+/// An acquired bus that deasserts the chip select line when dropped, also when a transaction is
+/// aborted (by a panic, or by dropping the transaction's future during a delay)
+struct Deselecting<'a> {
+    spi: AcquiredSPI<'a>,
+    /// Whether the last transfer left the chip select line asserted
+    selected: bool,
+}
+
+impl Deselecting<'_> {
+    fn transfer(&mut self, out: Option<&[u8]>, in_: Option<&mut [u8]>, last: bool) {
+        let len = out.map(|o| o.len()).or(in_.as_ref().map(|i| i.len()));
+        // unsafe: C API; buffers are valid, and of the passed length when both are present
+        unsafe {
+            self.raw_transfer(
+                out.map_or(core::ptr::null(), |o| o.as_ptr()),
+                in_.map_or(core::ptr::null_mut(), |i| i.as_mut_ptr()),
+                len.unwrap_or(0),
+                last,
+            )
+        };
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [u8], last: bool) {
+        // unsafe: C API; RIOT's transfers support using the same buffer for input and output
+        unsafe { self.raw_transfer(words.as_ptr(), words.as_mut_ptr(), words.len(), last) };
+    }
+
+    /// # Safety
+    ///
+    /// Both buffers need to be valid for `len` bytes, or null.
+    unsafe fn raw_transfer(&mut self, out: *const u8, in_: *mut u8, len: usize, last: bool) {
+        // unsafe: Forwarding the caller's guarantees
+        unsafe {
+            spi_transfer_bytes(
+                self.spi.device.0,
+                self.spi.cs,
+                !last,
+                out as *const _,
+                in_ as *mut _,
+                len as _,
+            )
+        };
+        self.selected = !last;
+    }
+}
+
+impl Drop for Deselecting<'_> {
+    fn drop(&mut self) {
+        if self.selected {
+            let dummy = 0u8;
+            // unsafe: Empty transfer, which only deasserts the chip select line (with a non-null
+            // output pointer, as some implementations assert that one buffer is given)
+            unsafe { self.raw_transfer(&dummy, core::ptr::null_mut(), 0, true) };
+        }
+    }
+}
@@ -4,6 +4,19 @@ use riot_sys::{
     spi_acquire, spi_clk_t, spi_cs_t, spi_mode_t, spi_release, spi_t, spi_transfer_bytes,
 };
 
+#[cfg(all(
+    feature = "embedded-hal-async",
+    riot_module_ztimer_usec,
+    riot_module_ztimer_msec
+))]
+mod impl_async;
+#[cfg(all(
+    feature = "embedded-hal-async",
+    riot_module_ztimer_usec,
+    riot_module_ztimer_msec
+))]
+pub use impl_async::AsyncSpiDevice;
+
 pub struct SPIDevice(spi_t);
 
 pub struct AcquiredSPI<'a> {
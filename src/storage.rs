@@ -0,0 +1,239 @@
+//! A small store for a single configuration record in internal flash
+//!
+//! The [Store] alternates between two [flash pages](crate::flashpage): Each save goes to the page
+//! that does not hold the latest record, and carries a sequence number and a checksum. If power
+//! is lost during a save, the interrupted page fails its checksum, and the previous record is
+//! still found on the other page.
+//!
+//! The record is stored as bytes; serializing and deserializing values is left to the application
+//! (eg. through `serde` with a compact format). With the `with_zerocopy` feature, plain data types
+//! can be stored directly through [Store::load_as] and [Store::save_as].
+//!
+//! ```no_run
+//! # use riot_wrappers::storage::Store;
+//! // The last two pages, which the linker script needs to keep free of firmware
+//! let numof = riot_wrappers::flashpage::numof();
+//! let store = Store::new(numof - 2, numof - 1);
+//!
+//! let mut buf = [0; 32];
+//! match store.load(&mut buf) {
+//!     Ok(Some(len)) => { /* use &buf[..len] */ }
+//!     _ => store.save(b"defaults").unwrap(),
+//! }
+//! ```
+
+use crate::error::NumericError;
+use crate::flashpage;
+
+/// Bytes identifying a record header ("RWcf")
+const MAGIC: u32 = 0x66635752;
+
+/// Length of the header data: magic, sequence number, length and checksum
+const HEADER_LEN: usize = 16;
+
+/// Space reserved for the header, which is written as a block of its own
+const HEADER_SPACE: usize = HEADER_LEN.next_multiple_of(flashpage::write_block_size());
+
+/// Size of the aligned buffer through which data is written
+const CHUNK: usize = {
+    let chunk = 64usize.next_multiple_of(HEADER_SPACE);
+    assert!(chunk % flashpage::write_block_size() == 0);
+    chunk
+};
+
+#[repr(align(8))]
+struct Chunk([u8; CHUNK]);
+
+// Keep in sync with the alignment of Chunk
+const _: () = assert!(
+    riot_sys::FLASHPAGE_WRITE_BLOCK_ALIGNMENT as usize <= 8,
+    "Flash write alignment exceeds what the store provides"
+);
+
+/// A record store on two flash pages; see the [module level documentation](self).
+///
+/// The store does not synchronize accesses: When used from multiple threads, it needs to be
+/// wrapped in a [Mutex](crate::mutex::Mutex).
+#[derive(Debug)]
+pub struct Store {
+    pages: [usize; 2],
+}
+
+/// Header of a record that passed its checks
+#[derive(Copy, Clone)]
+struct Valid {
+    sequence: u32,
+    len: usize,
+}
+
+impl Store {
+    /// Create a store on two distinct flash pages.
+    ///
+    /// The pages are only accessed when loading or saving. Anything in them that is not a valid
+    /// record is ignored when loading, and overwritten when saving.
+    pub const fn new(page_a: usize, page_b: usize) -> Self {
+        assert!(page_a != page_b, "A store needs two distinct pages");
+        Store {
+            pages: [page_a, page_b],
+        }
+    }
+
+    /// Largest record that can be saved
+    pub const fn capacity() -> usize {
+        flashpage::size() - HEADER_SPACE
+    }
+
+    /// Load the latest record into the buffer, and return its length
+    ///
+    /// `Ok(None)` is returned if no valid record was ever saved. A buffer that is too small for the
+    /// record produces an `EOVERFLOW` error.
+    pub fn load(&self, buf: &mut [u8]) -> Result<Option<usize>, NumericError> {
+        let Some((page, valid)) = self.latest()? else {
+            return Ok(None);
+        };
+        let buf = buf.get_mut(..valid.len).ok_or(crate::error::EOVERFLOW)?;
+        flashpage::read_at(page, HEADER_SPACE, buf)?;
+        Ok(Some(valid.len))
+    }
+
+    /// Save a record, replacing the latest one
+    ///
+    /// If saving is interrupted, the previous record stays available.
+    pub fn save(&self, data: &[u8]) -> Result<(), NumericError> {
+        if data.len() > Self::capacity() {
+            return Err(crate::error::EOVERFLOW);
+        }
+
+        let latest = self.latest()?;
+        let (page, sequence) = match latest {
+            Some((page, valid)) if page == self.pages[0] => {
+                (self.pages[1], valid.sequence.wrapping_add(1))
+            }
+            Some((_, valid)) => (self.pages[0], valid.sequence.wrapping_add(1)),
+            None => (self.pages[0], 0),
+        };
+
+        flashpage::erase(page)?;
+
+        // Data first: Only once it is complete, the header makes the record valid.
+        let mut chunk = Chunk([0xff; CHUNK]);
+        for (i, part) in data.chunks(CHUNK).enumerate() {
+            let padded = part.len().next_multiple_of(flashpage::write_block_size());
+            chunk.0[..part.len()].copy_from_slice(part);
+            chunk.0[part.len()..padded].fill(0xff);
+            flashpage::write_at(page, HEADER_SPACE + i * CHUNK, &chunk.0[..padded])?;
+        }
+
+        let mut header = Chunk([0xff; CHUNK]);
+        header.0[0..4].copy_from_slice(&MAGIC.to_le_bytes());
+        header.0[4..8].copy_from_slice(&sequence.to_le_bytes());
+        header.0[8..12].copy_from_slice(&(data.len() as u32).to_le_bytes());
+        header.0[12..16].copy_from_slice(&checksum(sequence, data).to_le_bytes());
+        flashpage::write_at(page, 0, &header.0[..HEADER_SPACE])
+    }
+
+    /// Load the latest record as a value of type `T`
+    ///
+    /// `Ok(None)` is returned if no valid record was ever saved, or if the record's length does
+    /// not match the type's size (eg. because a different type was saved).
+    ///
+    /// This is only available with the `with_zerocopy` feature.
+    #[cfg(feature = "with_zerocopy")]
+    pub fn load_as<T>(&self) -> Result<Option<T>, NumericError>
+    where
+        T: zerocopy::FromBytes + zerocopy::IntoBytes,
+    {
+        let Some((page, valid)) = self.latest()? else {
+            return Ok(None);
+        };
+        if valid.len != core::mem::size_of::<T>() {
+            return Ok(None);
+        }
+        let mut value = T::new_zeroed();
+        flashpage::read_at(page, HEADER_SPACE, value.as_mut_bytes())?;
+        Ok(Some(value))
+    }
+
+    /// Save a value of type `T` as the record, replacing the latest one
+    ///
+    /// This is only available with the `with_zerocopy` feature.
+    #[cfg(feature = "with_zerocopy")]
+    pub fn save_as<T>(&self, value: &T) -> Result<(), NumericError>
+    where
+        T: zerocopy::IntoBytes + zerocopy::Immutable,
+    {
+        self.save(value.as_bytes())
+    }
+
+    /// Find the page with the latest valid record
+    fn latest(&self) -> Result<Option<(usize, Valid)>, NumericError> {
+        let a = self.check(self.pages[0])?;
+        let b = self.check(self.pages[1])?;
+        Ok(match (a, b) {
+            (Some(a), Some(b)) if (b.sequence.wrapping_sub(a.sequence) as i32) > 0 => {
+                Some((self.pages[1], b))
+            }
+            (Some(a), _) => Some((self.pages[0], a)),
+            (None, Some(b)) => Some((self.pages[1], b)),
+            (None, None) => None,
+        })
+    }
+
+    /// Read a page's header, and verify the record's checksum
+    fn check(&self, page: usize) -> Result<Option<Valid>, NumericError> {
+        let mut header = [0; HEADER_LEN];
+        flashpage::read_at(page, 0, &mut header)?;
+        let field = |i: usize| u32::from_le_bytes(header[i..i + 4].try_into().unwrap());
+
+        let len = field(8) as usize;
+        if field(0) != MAGIC || len > Self::capacity() {
+            return Ok(None);
+        }
+        let sequence = field(4);
+
+        let mut crc = Crc32::new();
+        crc.update(&sequence.to_le_bytes());
+        let mut buf = [0; CHUNK];
+        let mut offset = 0;
+        while offset < len {
+            let part = &mut buf[..(len - offset).min(CHUNK)];
+            flashpage::read_at(page, HEADER_SPACE + offset, part)?;
+            crc.update(part);
+            offset += part.len();
+        }
+
+        Ok((crc.finish() == field(12)).then_some(Valid { sequence, len }))
+    }
+}
+
+fn checksum(sequence: u32, data: &[u8]) -> u32 {
+    let mut crc = Crc32::new();
+    crc.update(&sequence.to_le_bytes());
+    crc.update(data);
+    crc.finish()
+}
+
+/// Bitwise CRC-32 (as used in Ethernet and zlib)
+///
+/// Slow, but small; the amounts of data stored here do not warrant a table.
+struct Crc32(u32);
+
+impl Crc32 {
+    fn new() -> Self {
+        Crc32(0xffffffff)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.0 ^= *byte as u32;
+            for _ in 0..8 {
+                let mask = (self.0 & 1).wrapping_neg();
+                self.0 = (self.0 >> 1) ^ (0xedb88320 & mask);
+            }
+        }
+    }
+
+    fn finish(self) -> u32 {
+        !self.0
+    }
+}
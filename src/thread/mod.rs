@@ -25,9 +25,28 @@ mod tokenparts;
 pub use tokenparts::TokenParts;
 pub use tokenparts::{EndToken, InIsr, InThread, StartToken, ValueInThread};
 
+mod priority;
+pub use priority::Priority;
+
 mod stack_stats;
 pub use stack_stats::{StackStats, StackStatsError};
 
 /// Error returned by PID methods when no thread with that PID exists
 #[derive(Debug)]
 pub struct NoSuchThread;
+
+/// Error returned by [KernelPID::wakeup()]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum WakeupError {
+    /// Requested PID does not correspond to a thread
+    NoSuchThread,
+    /// The thread exists, but is not sleeping
+    NotSleeping,
+}
+
+impl From<NoSuchThread> for WakeupError {
+    fn from(_: NoSuchThread) -> Self {
+        WakeupError::NoSuchThread
+    }
+}
@@ -0,0 +1,49 @@
+/// Scheduling priority of a thread
+///
+/// Lower numbers indicate more important threads: Priority 0 is the highest, and
+/// [Priority::IDLE] is the lowest, reserved for the idle thread (if there is one).
+///
+/// Checks for the validity of the number happen when constructing the priority, which can be done
+/// in const context to evaluate the check at build time:
+///
+/// ```no_run
+/// # use riot_wrappers::thread::Priority;
+/// const WORKER: Priority = Priority::new(Priority::MAIN.get() - 1);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Priority(u8);
+
+impl Priority {
+    /// The highest priority, for threads with realtime requirements
+    pub const REALTIME: Self = Priority(0);
+    /// The priority of the main thread
+    #[doc(alias = "THREAD_PRIORITY_MAIN")]
+    pub const MAIN: Self = Priority::new(riot_sys::THREAD_PRIORITY_MAIN as _);
+    /// The lowest priority, which is the idle thread's
+    #[doc(alias = "THREAD_PRIORITY_IDLE")]
+    pub const IDLE: Self = Priority::new(riot_sys::THREAD_PRIORITY_IDLE as _);
+
+    /// Create a priority from its number
+    ///
+    /// ## Panics
+    ///
+    /// ... if the number is larger than that of [Priority::IDLE].
+    pub const fn new(priority: u8) -> Self {
+        assert!(
+            priority as u32 <= riot_sys::THREAD_PRIORITY_IDLE as u32,
+            "Priority exceeds the number of priority levels"
+        );
+        Priority(priority)
+    }
+
+    /// The priority's number
+    pub const fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+impl From<Priority> for u8 {
+    fn from(priority: Priority) -> u8 {
+        priority.0
+    }
+}
@@ -1,12 +1,12 @@
 //! RIOT (C) thread implementation
 use riot_sys as raw;
 
-use super::{NoSuchThread, StackStats, StackStatsError};
+use super::{NoSuchThread, StackStats, StackStatsError, WakeupError};
 use crate::helpers::PointerToCStr;
 
 /// Offloaded tools for creation
 mod creation;
-pub use creation::{scope, spawn, CountedThread, CountingThreadScope};
+pub use creation::{scope, spawn, spawn_fn, CountedThread, CountingThreadScope};
 
 /// Wrapper around a valid (not necessarily running, but in-range) [riot_sys::kernel_pid_t] that
 /// provides access to thread details and signaling.
@@ -133,12 +133,20 @@ impl KernelPID {
         }
     }
 
+    /// Wake up the thread if it is in the sleeping state (see [sleep()])
+    ///
+    /// RIOT only wakes threads that are sleeping: Threads blocked on anything else (eg. waiting
+    /// for a mutex or a message) are not affected, and produce [WakeupError::NotSleeping].
     #[doc(alias = "thread_wakeup")]
-    pub fn wakeup(&self) -> Result<(), NoSuchThread> {
+    pub fn wakeup(&self) -> Result<(), WakeupError> {
         let success = unsafe { raw::thread_wakeup(self.0) };
         match success {
             1 => Ok(()),
-            _ => Err(NoSuchThread),
+            // RIOT reports both cases alike; the status tells them apart.
+            _ => {
+                self.status()?;
+                Err(WakeupError::NotSleeping)
+            }
         }
     }
 
@@ -207,6 +215,22 @@ impl KernelPID {
         #[cfg(not(riot_develhelp))]
         return Err(StackStatsError::InformationUnavailable);
     }
+
+    /// The most stack the thread has used so far, in bytes (its stack's high-water mark)
+    ///
+    /// This is a shortcut to [StackStats::used] for tuning stack sizes, and returns None under the
+    /// same conditions in which [Self::stack_stats] fails.
+    pub fn stack_usage(&self) -> Option<usize> {
+        self.stack_stats().ok().map(|stats| stats.used())
+    }
+
+    /// The size of the thread's stack in bytes
+    ///
+    /// Like [Self::stack_usage], this returns None if the thread does not exist or develhelp is
+    /// not active.
+    pub fn stack_size(&self) -> Option<usize> {
+        self.stack_stats().ok().map(|stats| stats.size())
+    }
 }
 
 impl Into<raw::kernel_pid_t> for &KernelPID {
@@ -232,6 +256,18 @@ impl From<KernelPID> for core::num::NonZero<u16> {
 }
 
 /// PID of the currently active thread
+///
+/// ```
+/// # #![no_std]
+/// # #![feature(start)]
+/// # #[start]
+/// # fn main(_argc: isize, _argv: *const *const u8) -> isize {
+/// # use riot_wrappers::stdio::println;
+/// let pid = riot_wrappers::thread::get_pid();
+/// println!("Running in thread {:?} ({:?})", pid, riot_wrappers::thread::current_name());
+/// # 0
+/// # }
+/// ```
 #[doc(alias = "thread_getpid")]
 pub fn get_pid() -> KernelPID {
     // Ignoring the volatile in thread_getpid because it's probably not necessary (any application
@@ -239,9 +275,79 @@ pub fn get_pid() -> KernelPID {
     KernelPID(unsafe { raw::thread_getpid() })
 }
 
+/// Name of the currently active thread
+///
+/// Like [KernelPID::get_name], this is only available in DEVELHELP builds.
+#[doc(alias = "thread_getname")]
+pub fn current_name() -> Option<&'static str> {
+    if !cfg!(riot_develhelp) {
+        return None;
+    }
+
+    // unsafe: The current thread can not stop while this is running, and thread names are
+    // static strings (see KernelPID::get_name on how far that is relied on)
+    let ptr = unsafe { raw::thread_getname(raw::thread_getpid()) };
+    unsafe { ptr.to_lifetimed_cstr()? }.to_str().ok()
+}
+
 /// Put the current thread in the "sleeping" state, only to be continue when something calls
 /// [KernelPID::wakeup()] on its PID.
 #[doc(alias = "thread_sleep")]
 pub fn sleep() {
     unsafe { raw::thread_sleep() }
 }
+
+/// Let other threads of the same priority run before the current thread continues
+///
+/// Threads of higher priority preempt the current thread anyway; without other threads of the
+/// same priority ready to run, this returns right away.
+#[doc(alias = "thread_yield")]
+pub fn yield_now() {
+    // unsafe: C API makes no requirements
+    unsafe { raw::thread_yield() }
+}
+
+/// Snapshot of a thread's properties, as produced by [list()]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ThreadInfo {
+    pub pid: KernelPID,
+    /// The thread's name (only available in DEVELHELP builds)
+    pub name: Option<&'static str>,
+    pub priority: u8,
+    pub status: Status,
+    /// Information on the thread's stack (only available in DEVELHELP builds)
+    pub stack: Option<StackStats>,
+}
+
+/// Iterate over the threads that currently exist, similar to the information shown by the `ps`
+/// shell command
+///
+/// Each thread is inspected when the iterator gets to it, with interrupts disabled while its
+/// status, name and priority are read, so that these are consistent with each other. Threads
+/// that are started or stopped during the iteration may or may not show up.
+///
+/// The stack's usage is measured after interrupts are enabled again, as scanning the stack takes
+/// time proportional to its size.
+pub fn list() -> impl Iterator<Item = ThreadInfo> {
+    KernelPID::all_pids().filter_map(|pid| {
+        let (status, name, priority) = crate::interrupt::free(|_| {
+            let status = pid.status().ok()?;
+            let name = if cfg!(riot_develhelp) {
+                // unsafe: Thread names are static strings (see KernelPID::get_name)
+                let ptr = unsafe { raw::thread_getname(pid.0) };
+                unsafe { ptr.to_lifetimed_cstr() }.and_then(|name| name.to_str().ok())
+            } else {
+                None
+            };
+            Some((status, name, pid.priority().ok()?))
+        })?;
+        Some(ThreadInfo {
+            pid,
+            name,
+            priority,
+            status,
+            stack: pid.stack_stats().ok(),
+        })
+    })
+}
@@ -1,4 +1,5 @@
 use super::{KernelPID, Status};
+use crate::thread::Priority;
 
 use core::ffi::CStr;
 use core::intrinsics::transmute;
@@ -17,7 +18,7 @@ unsafe fn create<R>(
     stack: &mut [u8],
     closure: &mut R,
     name: &CStr,
-    priority: u8,
+    priority: Priority,
     flags: i32,
 ) -> (raw::kernel_pid_t, Option<*mut riot_sys::thread_t>)
 where
@@ -37,7 +38,7 @@ where
     let pid = raw::thread_create(
         transmute(stack.as_mut_ptr()),
         stack.len() as i32,
-        priority,
+        priority.get(),
         flags,
         Some(run::<R>),
         closure as *mut R as *mut _,
@@ -114,8 +115,8 @@ pub struct CountingThreadScope<'env, 'id> {
 
 impl<'env, 'id> CountingThreadScope<'env, 'id> {
     /// Start a thread in the given stack, in which the closure is run. The thread gets a human
-    /// readable name (ignored in no-DEVHELP mode), and is started with the priority and flags as
-    /// per thread_create documentation.
+    /// readable name (ignored in no-DEVHELP mode), and is started with the given priority and
+    /// flags as per thread_create documentation.
     ///
     /// The returned thread object can safely be discarded when the scope is not expected to ever
     /// return, and needs to be passed on to `.reap()` otherwise.
@@ -131,7 +132,7 @@ impl<'env, 'id> CountingThreadScope<'env, 'id> {
         stack: &'env mut [u8],
         closure: &'env mut R,
         name: &'env CStr,
-        priority: u8,
+        priority: Priority,
         flags: i32,
     ) -> Result<CountedThread<'id>, raw::kernel_pid_t>
     where
@@ -199,7 +200,7 @@ pub fn spawn<R>(
     stack: &'static mut [u8],
     closure: &'static mut R,
     name: &'static CStr,
-    priority: u8,
+    priority: Priority,
     flags: i32,
 ) -> Result<TrackedThread, raw::kernel_pid_t>
 where
@@ -217,6 +218,34 @@ where
     })
 }
 
+/// Create a thread with a statically allocated stack that runs a function item
+///
+/// This is a form of [spawn] for functions that do not capture any state: As function items
+/// are zero-sized, a `'static` reference to one can be created out of thin air, sparing the
+/// caller the `static mut` that would otherwise hold the closure.
+pub fn spawn_fn<F>(
+    stack: &'static mut [u8],
+    function: F,
+    name: &'static CStr,
+    priority: Priority,
+    flags: i32,
+) -> Result<TrackedThread, raw::kernel_pid_t>
+where
+    F: Send + FnMut(),
+{
+    const {
+        assert!(
+            core::mem::size_of::<F>() == 0,
+            "Only zero-sized functions can be spawned"
+        )
+    };
+    core::mem::forget(function);
+    // unsafe: A dangling pointer is valid for all accesses to a zero-sized type, and the value
+    // was moved in (and will never be dropped, as the thread's closure is never dropped either).
+    let closure: &'static mut F = unsafe { &mut *core::ptr::NonNull::dangling().as_ptr() };
+    spawn(stack, closure, name, priority, flags)
+}
+
 /// A thread identified not only by its PID (which can be reused whenever the thread has quit) but
 /// also by a pointer to its thread control block. This gives a TrackedThread a better get_status()
 /// method that reliably reports Stopped even when the PID is reused.
@@ -1,5 +1,11 @@
 /// Gathered information about a thread, returned by [super::KernelPID::stack_stats()].
 ///
+/// The free space is measured (through `thread_measure_stack_free`) as the part of the stack
+/// that was never written to, so the used stack is the thread's maximum stack usage so far.
+///
+/// For the current thread, these are obtained through
+/// `riot_wrappers::thread::get_pid().stack_stats()`.
+///
 /// All accessors are unconditional, because the StackStats can't be obtained without develhelp in
 /// the first place.
 #[derive(Debug)]
@@ -31,6 +37,16 @@ impl StackStats {
     pub fn used(&self) -> usize {
         self.size - self.free
     }
+
+    /// Share of the stack that was used, in percent (rounded down)
+    ///
+    /// As with [Self::used], this is a high-water mark, not the current use.
+    pub fn usage_percent(&self) -> u8 {
+        if self.size == 0 {
+            return 0;
+        }
+        (self.used() as u64 * 100 / self.size as u64) as u8
+    }
 }
 
 #[non_exhaustive]
@@ -0,0 +1,132 @@
+//! A byte queue that can be shared between interrupts and threads, built on RIOT's
+//! [thread safe ringbuffer](https://doc.riot-os.org/tsrb_8h.html)
+//!
+//! Unlike the [ringbuffer](crate::ringbuffer), this can be accessed through a shared reference:
+//! Every operation disables interrupts while it runs. This makes it suitable for passing bytes
+//! from an interrupt handler (eg. a UART's receive callback) to a thread.
+//!
+//! ```no_run
+//! # use riot_wrappers::tsrb::Tsrb;
+//! static mut BUFFER: [u8; 64] = [0; 64];
+//! let queue = Tsrb::new(unsafe { &mut *core::ptr::addr_of_mut!(BUFFER) });
+//! // in the interrupt handler
+//! queue.add(b"hello");
+//! // in the thread
+//! let mut buf = [0; 16];
+//! let len = queue.get(&mut buf);
+//! ```
+
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+
+/// A fixed-capacity queue of bytes in a static buffer
+pub struct Tsrb {
+    rb: UnsafeCell<riot_sys::tsrb_t>,
+}
+
+// unsafe: The buffer is 'static, and all accesses go through C functions that disable interrupts
+// while they run.
+unsafe impl Send for Tsrb {}
+unsafe impl Sync for Tsrb {}
+
+impl Tsrb {
+    /// Create an empty queue in the buffer
+    ///
+    /// ## Panics
+    ///
+    /// This panics if the buffer's length is not a power of two (or too large to express for
+    /// `tsrb_t`).
+    #[doc(alias = "tsrb_init")]
+    pub fn new(buffer: &'static mut [u8]) -> Self {
+        assert!(
+            buffer.len().is_power_of_two(),
+            "Buffer size needs to be a power of two"
+        );
+        let size: riot_sys::libc::c_uint = buffer
+            .len()
+            .try_into()
+            .expect("Buffer size exceeds what tsrb_t can express");
+
+        let mut rb = MaybeUninit::uninit();
+        // unsafe: C function initializes the struct; the buffer is valid forever.
+        let rb = unsafe {
+            riot_sys::inline::tsrb_init(
+                crate::inline_cast_mut(rb.as_mut_ptr()),
+                buffer.as_mut_ptr(),
+                size,
+            );
+            rb.assume_init()
+        };
+        Tsrb {
+            rb: UnsafeCell::new(rb),
+        }
+    }
+
+    /// Append as many bytes of the data as fit, and return their number
+    #[doc(alias = "tsrb_add")]
+    pub fn add(&self, data: &[u8]) -> usize {
+        // unsafe: C API on an initialized struct
+        (unsafe { riot_sys::tsrb_add(self.rb.get(), data.as_ptr(), data.len() as _) }) as _
+    }
+
+    /// Append a byte, or return false if the queue is full
+    #[doc(alias = "tsrb_add_one")]
+    pub fn add_one(&self, byte: u8) -> bool {
+        // unsafe: C API on an initialized struct
+        (unsafe { riot_sys::tsrb_add_one(self.rb.get(), byte) }) == 0
+    }
+
+    /// Move as many of the oldest bytes into the buffer as fit or are available, and return
+    /// their number
+    #[doc(alias = "tsrb_get")]
+    pub fn get(&self, buf: &mut [u8]) -> usize {
+        // unsafe: C API on an initialized struct
+        (unsafe { riot_sys::tsrb_get(self.rb.get(), buf.as_mut_ptr(), buf.len() as _) }) as _
+    }
+
+    /// Remove the oldest byte and return it
+    #[doc(alias = "tsrb_get_one")]
+    pub fn get_one(&self) -> Option<u8> {
+        // unsafe: C API on an initialized struct
+        let byte = unsafe { riot_sys::tsrb_get_one(self.rb.get()) };
+        // The byte is returned as an unsigned value, so only errors are negative
+        byte.try_into().ok()
+    }
+
+    /// Number of bytes currently in the queue
+    #[doc(alias = "tsrb_avail")]
+    pub fn avail(&self) -> usize {
+        // unsafe: C API on an initialized struct
+        (unsafe { riot_sys::inline::tsrb_avail(crate::inline_cast(self.rb.get())) }) as _
+    }
+
+    /// Number of bytes that can currently be added to the queue
+    #[doc(alias = "tsrb_free")]
+    pub fn free(&self) -> usize {
+        // unsafe: C API on an initialized struct
+        (unsafe { riot_sys::inline::tsrb_free(crate::inline_cast(self.rb.get())) }) as _
+    }
+
+    #[doc(alias = "tsrb_empty")]
+    pub fn is_empty(&self) -> bool {
+        // unsafe: C API on an initialized struct
+        unsafe { riot_sys::inline::tsrb_empty(crate::inline_cast(self.rb.get())) != 0 }
+    }
+
+    #[doc(alias = "tsrb_full")]
+    pub fn is_full(&self) -> bool {
+        // unsafe: C API on an initialized struct
+        unsafe { riot_sys::inline::tsrb_full(crate::inline_cast(self.rb.get())) != 0 }
+    }
+}
+
+impl core::fmt::Debug for Tsrb {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(
+            fmt,
+            "Tsrb {{ {} bytes used, {} free }}",
+            self.avail(),
+            self.free()
+        )
+    }
+}
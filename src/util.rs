@@ -0,0 +1,44 @@
+//! Small data structures that are not tied to any particular RIOT API
+
+/// A fixed set of key-value pairs, searched linearly
+///
+/// This is intended for small dispatch tables (eg. mapping numeric identifiers to handlers) that
+/// are known at build time and do not need any allocation:
+///
+/// ```
+/// # use riot_wrappers::util::StaticMap;
+/// static HANDLERS: StaticMap<u8, fn() -> &'static str, 2> =
+///     StaticMap::new([(1, || "one"), (2, || "two")]);
+///
+/// assert_eq!(HANDLERS.get(&2).map(|f| f()), Some("two"));
+/// assert!(HANDLERS.get(&3).is_none());
+/// ```
+///
+/// As lookups compare every key, this is only suitable for small N (say, up to 32). If a key
+/// occurs multiple times, lookups find the first entry.
+#[derive(Debug, Clone)]
+pub struct StaticMap<K, V, const N: usize> {
+    entries: [(K, V); N],
+}
+
+impl<K: PartialEq, V, const N: usize> StaticMap<K, V, N> {
+    pub const fn new(entries: [(K, V); N]) -> Self {
+        Self { entries }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries
+            .iter_mut()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Iterate over all key-value pairs, in the order they were given in
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+}
@@ -0,0 +1,19 @@
+//! Identification of the software the program is built from
+//!
+//! Like the [board] information, these are fixed at build time; they are useful to include in
+//! bug reports or in diagnostic output at startup.
+//!
+//! [board]: crate::board
+
+/// The version of RIOT the program is built against (eg. `"2024.10"`, or a `git describe` output
+/// for development versions)
+#[doc(alias = "RIOT_VERSION")]
+pub const fn riot_version() -> &'static str {
+    crate::build_string(riot_sys::RIOT_VERSION)
+}
+
+/// The name of the application (the `APPLICATION` make variable)
+#[doc(alias = "RIOT_APPLICATION")]
+pub const fn application() -> &'static str {
+    crate::build_string(riot_sys::RIOT_APPLICATION)
+}
@@ -10,7 +10,8 @@
 //!
 //! ## Incomplete
 //!
-//! So far, only a subset of VFS is implemented; in particular, the file system is read-only.
+//! So far, only a subset of VFS is implemented; in particular, there is no creation or removal of
+//! directories, and no renaming or removal of files.
 
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
@@ -89,14 +90,104 @@ pub enum SeekFrom {
     Current(isize),
 }
 
+/// Options for opening a [File] in modes other than read-only
+///
+/// It is analogous to [std::fs::OpenOptions](https://doc.rust-lang.org/std/fs/struct.OpenOptions.html):
+///
+/// ```no_run
+/// # use riot_wrappers::vfs::OpenOptions;
+/// let mut log = OpenOptions::new()
+///     .append(true)
+///     .create(true)
+///     .open("/sd0/log.txt")
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OpenOptions {
+    read: bool,
+    write: bool,
+    append: bool,
+    create: bool,
+    truncate: bool,
+}
+
+impl OpenOptions {
+    /// Create options with all flags cleared
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the file for reading
+    pub fn read(&mut self, read: bool) -> &mut Self {
+        self.read = read;
+        self
+    }
+
+    /// Open the file for writing (starting at the beginning of the file)
+    pub fn write(&mut self, write: bool) -> &mut Self {
+        self.write = write;
+        self
+    }
+
+    /// Open the file for writing at its end (`O_APPEND`); this implies [Self::write].
+    pub fn append(&mut self, append: bool) -> &mut Self {
+        self.append = append;
+        self
+    }
+
+    /// Create the file if it does not exist (`O_CREAT`)
+    pub fn create(&mut self, create: bool) -> &mut Self {
+        self.create = create;
+        self
+    }
+
+    /// Truncate the file to zero length when opening (`O_TRUNC`)
+    pub fn truncate(&mut self, truncate: bool) -> &mut Self {
+        self.truncate = truncate;
+        self
+    }
+
+    fn flags(&self) -> libc::c_int {
+        let write = self.write || self.append;
+        let mut flags = match (self.read, write) {
+            (true, true) => riot_sys::O_RDWR,
+            (false, true) => riot_sys::O_WRONLY,
+            _ => riot_sys::O_RDONLY,
+        };
+        if self.append {
+            flags |= riot_sys::O_APPEND;
+        }
+        if self.create {
+            flags |= riot_sys::O_CREAT;
+        }
+        if self.truncate {
+            flags |= riot_sys::O_TRUNC;
+        }
+        flags as _
+    }
+
+    /// Open a file with the configured options.
+    #[doc(alias = "vfs_open")]
+    pub fn open(&self, path: &str) -> Result<File, NumericError> {
+        File::open_with_flags(path, self.flags())
+    }
+}
+
 impl File {
     /// Open a file in read-only mode.
+    ///
+    /// See [OpenOptions] for other modes.
+    #[doc(alias = "vfs_open")]
     pub fn open(path: &str) -> Result<Self, NumericError> {
+        Self::open_with_flags(path, riot_sys::O_RDONLY as _)
+    }
+
+    fn open_with_flags(path: &str, flags: libc::c_int) -> Result<Self, NumericError> {
         let path = NameNullTerminated::new(path)?;
-        let fileno = unsafe {
-            riot_sys::vfs_open(path.as_cstr()?.as_ptr() as _, riot_sys::O_RDONLY as _, 0)
-        }
-        .negative_to_error()?;
+        // unsafe: C API with a nul terminated string. The mode is only relevant when creating
+        // files, and ignored by the file systems that support VFS.
+        let fileno = unsafe { riot_sys::vfs_open(path.as_cstr()?.as_ptr() as _, flags, 0) }
+            .negative_to_error()?;
         Ok(File {
             fileno,
             _not_send_sync: PhantomData,
@@ -125,6 +216,47 @@ impl File {
         .map(|len| len as _)
     }
 
+    /// Write the buffer at the current cursor position in the file, and advance the cursor by the
+    /// written length, which is also returned.
+    ///
+    /// The written length may be shorter than the buffer (eg. if the file system is full).
+    #[doc(alias = "vfs_write")]
+    pub fn write(&mut self, buf: &[u8]) -> Result<usize, NumericError> {
+        (unsafe {
+            riot_sys::vfs_write(
+                self.fileno,
+                buf.as_ptr() as *const libc::c_void,
+                buf.len() as _,
+            )
+        })
+        .negative_to_error()
+        .map(|len| len as _)
+    }
+
+    /// Write the complete buffer, repeating [Self::write] as necessary.
+    pub fn write_all(&mut self, mut buf: &[u8]) -> Result<(), NumericError> {
+        while !buf.is_empty() {
+            match self.write(buf)? {
+                0 => return Err(NumericError::from_constant(riot_sys::ENOSPC as _)),
+                n => buf = &buf[n..],
+            }
+        }
+        Ok(())
+    }
+
+    /// Close the file, reporting any errors that occur in the process
+    ///
+    /// Dropping the file closes it too, but does not report errors, which may indicate that data
+    /// written earlier was not persisted.
+    #[doc(alias = "vfs_close")]
+    pub fn close(self) -> Result<(), NumericError> {
+        let fileno = self.fileno;
+        core::mem::forget(self);
+        (unsafe { riot_sys::vfs_close(fileno) })
+            .negative_to_error()
+            .map(|_| ())
+    }
+
     /// Move the file cursor to the indicated position.
     pub fn seek(&mut self, pos: SeekFrom) -> Result<usize, NumericError> {
         let (off, whence) = match pos {
@@ -138,6 +270,12 @@ impl File {
     }
 }
 
+impl core::fmt::Write for File {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.write_all(s.as_bytes()).map_err(|_| core::fmt::Error)
+    }
+}
+
 impl Drop for File {
     fn drop(&mut self) {
         unsafe { riot_sys::vfs_close(self.fileno) };
@@ -200,8 +338,19 @@ impl<'d> Dir<'d> {
     /// Unlike files (which are plain numeric file handles in RIOT), an open directory is a data
     /// structure, and depending on the underlying file system may be a linked list. Therefore, we
     /// can not return the open directory (and move it in the course of that), but need its place
-    /// to be pre-pinned. A simple `pin!(&mut Default::default())` will to to get a suitable
-    /// `slot`.
+    /// to be pre-pinned. A simple `pin!(Default::default())` will do to get a suitable `slot`.
+    ///
+    /// The directory is closed when the returned [Dir] is dropped, no matter whether it was
+    /// iterated over completely.
+    ///
+    /// ```no_run
+    /// # use core::pin::pin;
+    /// # use riot_wrappers::vfs::Dir;
+    /// # use riot_wrappers::stdio::println;
+    /// for entry in Dir::open("/sd0", pin!(Default::default())).unwrap() {
+    ///     println!("{}", entry.name());
+    /// }
+    /// ```
     pub fn open<'name>(
         name: &'name str,
         mut slot: Pin<&'d mut DirSlot>,
@@ -250,7 +399,7 @@ impl<'d> Iterator for Dir<'d> {
     }
 }
 
-/// Directory entry inside a file
+/// Directory entry inside a directory
 ///
 /// The entry primarily indicates the file's name.
 pub struct Dirent<'d>(riot_sys::vfs_dirent_t, PhantomData<&'d ()>);
@@ -278,6 +427,14 @@ impl Dirent<'_> {
     }
 }
 
+impl core::fmt::Debug for Dirent<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Dirent")
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
 /// A mount point, represented (and made un-unmountable) by its root directory
 pub struct Mount<'a>(&'a mut riot_sys::vfs_DIR);
 
@@ -0,0 +1,77 @@
+//! Access to the hardware [watchdog timer](https://doc.riot-os.org/group__drivers__periph__wdt.html)
+//!
+//! Once started, the watchdog reboots the device unless it is kicked within the configured time.
+//!
+//! ```no_run
+//! # use riot_wrappers::wdt::Watchdog;
+//! let watchdog = Watchdog::start(0, 5000).unwrap();
+//! loop {
+//!     // do work, taking less than the returned watchdog.max_ms() per iteration
+//!     watchdog.kick();
+//! }
+//! ```
+
+use crate::error::NumericError;
+
+/// A running watchdog
+///
+/// There is only one watchdog in the system, and no exclusivity is enforced: Starting it twice
+/// reconfigures the time bounds, and kicking through any handle kicks the one watchdog.
+///
+/// Dropping the handle does *not* stop the watchdog; a device whose application lost track of
+/// the handle is just as stuck as one that stopped kicking, and should be rebooted. This is also
+/// why there is no `stop` method: Not all hardware supports stopping the watchdog once it was
+/// started.
+#[derive(Debug)]
+pub struct Watchdog {
+    min_ms: u32,
+    max_ms: u32,
+}
+
+impl Watchdog {
+    /// Configure the watchdog to reboot the device, and start it.
+    ///
+    /// A `min_ms` of 0 sets up a plain watchdog that needs to be kicked within `max_ms`
+    /// milliseconds; nonzero values (if supported by the hardware) set up a windowed watchdog that
+    /// also reboots if it is kicked earlier than `min_ms` milliseconds after the last kick.
+    ///
+    /// The maximum time is clamped into the range supported by the hardware; the bounds that were
+    /// actually configured are available through [Self::min_ms] and [Self::max_ms]. An error is
+    /// returned if the minimum time does not leave any window for kicking.
+    #[doc(alias = "wdt_setup_reboot")]
+    #[doc(alias = "wdt_start")]
+    pub fn start(min_ms: u32, max_ms: u32) -> Result<Self, NumericError> {
+        let max_ms = max_ms.clamp(
+            riot_sys::NWDT_TIME_LOWER_LIMIT as _,
+            riot_sys::NWDT_TIME_UPPER_LIMIT as _,
+        );
+        if min_ms >= max_ms {
+            return Err(crate::error::EINVAL);
+        }
+
+        // unsafe: C API, with the bounds checked as asserted by the C side
+        unsafe {
+            riot_sys::wdt_setup_reboot(min_ms, max_ms);
+            riot_sys::wdt_start();
+        }
+
+        Ok(Watchdog { min_ms, max_ms })
+    }
+
+    /// Reset the watchdog's timer
+    #[doc(alias = "wdt_kick")]
+    pub fn kick(&self) {
+        // unsafe: C API on a watchdog that was set up
+        unsafe { riot_sys::wdt_kick() };
+    }
+
+    /// The configured minimum time between kicks, in milliseconds
+    pub fn min_ms(&self) -> u32 {
+        self.min_ms
+    }
+
+    /// The configured maximum time between kicks, in milliseconds
+    pub fn max_ms(&self) -> u32 {
+        self.max_ms
+    }
+}
@@ -0,0 +1,47 @@
+//! Conversions between [core::time::Duration] and tick counts of clocks with a given frequency
+//!
+//! These are the building blocks for the conversions on [Ticks](super::Ticks), and can be used
+//! where the frequency of a clock is only known at runtime.
+//!
+//! Durations are always rounded up to the next full tick (as ZTimer would not necessarily wait
+//! long enough otherwise), while tick counts are rounded down to the nanosecond. Durations that
+//! exceed what a 32-bit tick count can express are handled explicitly: either the conversion
+//! fails ([checked_duration_to_ticks]), or it saturates at `u32::MAX` ticks
+//! ([duration_to_ticks]), which is around 49 days on a millisecond clock, but only 71 minutes on
+//! a microsecond clock.
+
+use core::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Number of ticks of a clock running at `clock_freq` Hz that make up at least the given duration,
+/// or None if that exceeds `u32::MAX`.
+pub const fn checked_duration_to_ticks(clock_freq: u32, d: Duration) -> Option<u32> {
+    let ticks = (d.as_nanos() * clock_freq as u128).div_ceil(NANOS_PER_SEC);
+    if ticks > u32::MAX as u128 {
+        None
+    } else {
+        Some(ticks as u32)
+    }
+}
+
+/// Number of ticks of a clock running at `clock_freq` Hz that make up at least the given
+/// duration, saturating at `u32::MAX`
+pub const fn duration_to_ticks(clock_freq: u32, d: Duration) -> u32 {
+    match checked_duration_to_ticks(clock_freq, d) {
+        Some(t) => t,
+        None => u32::MAX,
+    }
+}
+
+/// Duration of a number of ticks of a clock running at `clock_freq` Hz
+///
+/// ## Panics
+///
+/// This panics if the clock frequency is 0.
+pub const fn ticks_to_duration(clock_freq: u32, ticks: u32) -> Duration {
+    // Can not overflow: both factors are below 2^32
+    let nanos = ticks as u128 * NANOS_PER_SEC / clock_freq as u128;
+    // Can not truncate: even at 1 Hz, this is less than 2^63 nanoseconds
+    Duration::from_nanos(nanos as u64)
+}
@@ -9,12 +9,16 @@
 //! because sleeping for a Duration works infallibly (even if the duration exceeds the maximum
 //! number of ticks a timer can sleep) by sleeping in repetitions.
 
+pub mod conv;
 #[cfg(riot_module_ztimer_periodic)]
 pub mod periodic;
 
 use core::convert::TryInto;
+use core::marker::PhantomPinned;
 use core::mem::ManuallyDrop;
 use core::pin::Pin;
+#[cfg(feature = "with_embedded_hal")]
+use core::sync::atomic::{AtomicBool, Ordering};
 
 use pin_project::{pin_project, pinned_drop};
 
@@ -67,11 +71,21 @@ impl<const HZ: u32> ValueInThread<Clock<HZ>> {
         unsafe { riot_sys::ztimer_sleep(self.0, duration.0) };
     }
 
-    /// Keep the current thread in a busy loop until the duration of ticks in the timer's tim scale
-    /// has passed
+    /// Keep the current thread in a busy loop until the duration of ticks in the timer's time
+    /// scale has passed
     ///
     /// Quoting the original documentation, "This blocks lower priority threads. Use only for
-    /// *very* short delays.".
+    /// *very* short delays.". Unlike [Self::sleep], this does not suffer from the latency of
+    /// switching threads, which makes it suitable for the delays of bit-banged protocols:
+    ///
+    /// ```no_run
+    /// # use riot_wrappers::ztimer::{Clock, Ticks};
+    /// # let usec = Clock::usec();
+    /// // Hold a line for 10µs
+    /// usec.spin(Ticks(10));
+    /// ```
+    ///
+    /// Interrupts are still served while spinning, so the delay may be longer than requested.
     ///
     /// Wraps [ztimer_spin](https://doc.riot-os.org/group__sys__ztimer.html#ga9de3d9e3290746b856bb23eb2dccaa7c)
     ///
@@ -95,7 +109,12 @@ impl<const HZ: u32> ValueInThread<Clock<HZ>> {
     /// the system from entering deeper sleep modes).
     pub fn sleep_extended(&self, duration: core::time::Duration) {
         // Convert to ticks, rounding up as per Duration documentation
-        let mut ticks = (duration * HZ - core::time::Duration::new(0, 1)).as_secs() + 1;
+        let Some(rounded_down) = (duration * HZ).checked_sub(core::time::Duration::new(0, 1))
+        else {
+            // Zero duration
+            return;
+        };
+        let mut ticks = rounded_down.as_secs() + 1;
         while ticks > u32::MAX.into() {
             self.sleep(Ticks(u32::MAX));
             ticks -= u64::from(u32::MAX);
@@ -144,6 +163,11 @@ impl<const HZ: u32> ValueInThread<Clock<HZ>> {
 }
 
 impl<const HZ: u32> Clock<HZ> {
+    /// The underlying clock, for use with ztimer functions that are wrapped in other modules
+    pub(crate) fn as_ptr(&self) -> *mut ztimer_clock_t {
+        self.0
+    }
+
     /// Similar to [`.sleep()`], but this does not block but creates a future to be
     /// `.await`ed.
     ///
@@ -444,9 +468,6 @@ impl embedded_hal_async::delay::DelayNs for Delay {
 }
 
 impl<const F: u32> embedded_hal::delay::DelayNs for ValueInThread<Clock<F>> {
-    // FIXME: Provide delay_us and delay_ms, at least for the clocks where those fit, to avoid the
-    // loops where the provided function wakes up every 4.3s
-
     #[inline(always)]
     fn delay_ns(&mut self, ns: u32) {
         if F > NANOS_PER_SEC {
@@ -467,6 +488,231 @@ impl<const F: u32> embedded_hal::delay::DelayNs for ValueInThread<Clock<F>> {
             self.sleep(Ticks(ticks as u32));
         }
     }
+
+    // Overridden to avoid the provided functions' loops that wake up every 4.3s; sleep_extended
+    // only loops when the clock's range is exceeded.
+
+    fn delay_us(&mut self, us: u32) {
+        self.sleep_extended(core::time::Duration::from_micros(us.into()));
+    }
+
+    fn delay_ms(&mut self, ms: u32) {
+        self.sleep_extended(core::time::Duration::from_millis(ms.into()));
+    }
+}
+
+impl<const F: u32, T: Into<u32>> embedded_hal_0_2::blocking::delay::DelayMs<T>
+    for ValueInThread<Clock<F>>
+{
+    fn delay_ms(&mut self, ms: T) {
+        self.sleep_extended(core::time::Duration::from_millis(ms.into().into()));
+    }
+}
+
+impl<const F: u32, T: Into<u32>> embedded_hal_0_2::blocking::delay::DelayUs<T>
+    for ValueInThread<Clock<F>>
+{
+    fn delay_us(&mut self, us: T) {
+        self.sleep_extended(core::time::Duration::from_micros(us.into().into()));
+    }
+}
+
+/// A one-shot timer that can be started and then polled for whether it has expired
+///
+/// This provides the [embedded_hal_0_2::timer::CountDown] and [embedded_hal_0_2::timer::Cancel]
+/// traits used by many drivers for timeout management. Counts passed to those are in ticks of the
+/// clock, ie. in microseconds when created from [Clock::usec()].
+///
+/// As ZTimer keeps a reference to the timer while it is running, the traits are implemented on a
+/// pinned countdown; a simple `pin!(Countdown::new(clock))` will do to get one.
+///
+/// This is only available with the `with_embedded_hal` feature.
+#[cfg(feature = "with_embedded_hal")]
+#[pin_project(PinnedDrop)]
+pub struct Countdown<const HZ: u32> {
+    clock: Clock<HZ>,
+    #[pin]
+    timer: riot_sys::ztimer_t,
+    // Referenced from the timer's arg whenever the timer is set
+    expired: AtomicBool,
+    #[pin]
+    // riot_sys::ztimer_t is Unpin because riot-sys doesn't know any better
+    _pin: PhantomPinned,
+}
+
+/// Error returned when cancelling a [Countdown] that is not running
+#[cfg(feature = "with_embedded_hal")]
+#[derive(Debug)]
+pub struct NotRunning;
+
+#[cfg(feature = "with_embedded_hal")]
+impl<const HZ: u32> Countdown<HZ> {
+    /// Create a countdown on a clock; it is not started yet.
+    pub fn new(clock: Clock<HZ>) -> Self {
+        Countdown {
+            clock,
+            timer: Default::default(),
+            expired: AtomicBool::new(false),
+            _pin: PhantomPinned,
+        }
+    }
+
+    extern "C" fn expire(arg: *mut riot_sys::libc::c_void) {
+        // unsafe: Set from a pinned &AtomicBool that outlives the set timer
+        let expired = unsafe { &*(arg as *const AtomicBool) };
+        expired.store(true, Ordering::Release);
+    }
+
+    /// Start (or restart) the countdown to expire after the given duration.
+    #[doc(alias = "ztimer_set")]
+    pub fn start_ticks(self: Pin<&mut Self>, duration: Ticks<HZ>) {
+        let projected = self.project();
+        let timer = projected.timer.get_mut();
+
+        // unsafe: OK per C API. The timer is removed first, so that nothing fires while the
+        // expiry flag is being reset.
+        unsafe { riot_sys::ztimer_remove(projected.clock.0, timer) };
+        projected.expired.store(false, Ordering::Relaxed);
+
+        timer.callback = Some(Self::expire);
+        timer.arg = projected.expired as *const AtomicBool as *mut _;
+
+        // unsafe: OK per C API; the timer and the flag are pinned, and the timer is removed in
+        // the drop.
+        unsafe { riot_sys::ztimer_set(projected.clock.0, timer, duration.0) };
+    }
+
+    /// Check whether the countdown has run out since it was last started
+    pub fn is_expired(&self) -> bool {
+        self.expired.load(Ordering::Acquire)
+    }
+
+    /// Stop the countdown, returning whether it was still running.
+    #[doc(alias = "ztimer_remove")]
+    pub fn stop(self: Pin<&mut Self>) -> bool {
+        let projected = self.project();
+        // unsafe: OK per C API
+        unsafe { riot_sys::ztimer_remove(projected.clock.0, projected.timer.get_mut()) }
+    }
+}
+
+#[cfg(feature = "with_embedded_hal")]
+#[pinned_drop]
+impl<const HZ: u32> PinnedDrop for Countdown<HZ> {
+    fn drop(self: Pin<&mut Self>) {
+        self.stop();
+    }
+}
+
+#[cfg(feature = "with_embedded_hal")]
+impl<const HZ: u32> embedded_hal_0_2::timer::CountDown for Pin<&mut Countdown<HZ>> {
+    type Time = u32;
+
+    fn start<T>(&mut self, count: T)
+    where
+        T: Into<u32>,
+    {
+        self.as_mut().start_ticks(Ticks(count.into()));
+    }
+
+    fn wait(&mut self) -> nb::Result<(), void::Void> {
+        match self.is_expired() {
+            true => Ok(()),
+            false => Err(nb::Error::WouldBlock),
+        }
+    }
+}
+
+#[cfg(feature = "with_embedded_hal")]
+impl<const HZ: u32> embedded_hal_0_2::timer::Cancel for Pin<&mut Countdown<HZ>> {
+    type Error = NotRunning;
+
+    fn cancel(&mut self) -> Result<(), NotRunning> {
+        match self.as_mut().stop() {
+            true => Ok(()),
+            false => Err(NotRunning),
+        }
+    }
+}
+
+/// A timer that runs a callback once, some time after it was started
+///
+/// The callback is executed in the timer's interrupt context; consequently, it needs to be
+/// [Send], and must not block.
+///
+/// As ZTimer keeps a reference to the timer (and the timer to the callback) while it is running,
+/// it is started through a pinned reference: `let timer = pin!(OneShot::new(clock, callback));
+/// timer.start(ticks);`. When the timer is dropped before the callback ran, the callback is
+/// cancelled.
+#[pin_project(PinnedDrop)]
+pub struct OneShot<F: FnMut() + Send, const HZ: u32> {
+    clock: Clock<HZ>,
+    #[pin]
+    timer: riot_sys::ztimer_t,
+    // Referenced from the timer's arg whenever the timer is set
+    callback: F,
+    #[pin]
+    // riot_sys::ztimer_t is Unpin because riot-sys doesn't know any better
+    _pin: PhantomPinned,
+}
+
+impl<F: FnMut() + Send, const HZ: u32> OneShot<F, HZ> {
+    /// Create a timer on a clock; it is not started yet.
+    pub fn new(clock: Clock<HZ>, callback: F) -> Self {
+        OneShot {
+            clock,
+            timer: Default::default(),
+            callback,
+            _pin: PhantomPinned,
+        }
+    }
+
+    extern "C" fn call(arg: *mut riot_sys::libc::c_void) {
+        // unsafe: Set from the pinned callback, which outlives the set timer. Reference
+        // construction: The thread does not access the callback while the timer is set.
+        let callback = unsafe { &mut *(arg as *mut F) };
+        callback();
+    }
+
+    /// Start (or restart) the timer to run the callback after the given duration.
+    #[doc(alias = "ztimer_set")]
+    pub fn start(self: Pin<&mut Self>, duration: Ticks<HZ>) {
+        let projected = self.project();
+        let timer = projected.timer.get_mut();
+
+        // unsafe: OK per C API. The timer is removed first, so that the callback is not accessed
+        // from the interrupt while it is referenced here.
+        unsafe { riot_sys::ztimer_remove(projected.clock.0, timer) };
+
+        timer.callback = Some(Self::call);
+        timer.arg = projected.callback as *mut F as *mut _;
+
+        // unsafe: OK per C API; the timer and the callback are pinned, and the timer is removed
+        // in the drop.
+        unsafe { riot_sys::ztimer_set(projected.clock.0, timer, duration.0) };
+    }
+
+    /// Check whether the timer has been started and the callback has not run yet
+    #[doc(alias = "ztimer_is_set")]
+    pub fn is_pending(&self) -> bool {
+        // unsafe: OK per C API
+        unsafe { riot_sys::ztimer_is_set(self.clock.0, &self.timer) != 0 }
+    }
+
+    /// Cancel the timer, returning whether the callback was still pending.
+    #[doc(alias = "ztimer_remove")]
+    pub fn cancel(self: Pin<&mut Self>) -> bool {
+        let projected = self.project();
+        // unsafe: OK per C API
+        unsafe { riot_sys::ztimer_remove(projected.clock.0, projected.timer.get_mut()) }
+    }
+}
+
+#[pinned_drop]
+impl<F: FnMut() + Send, const HZ: u32> PinnedDrop for OneShot<F, HZ> {
+    fn drop(self: Pin<&mut Self>) {
+        self.cancel();
+    }
 }
 
 /// The error type of fallible conversions to ticks.
@@ -531,6 +777,27 @@ impl<const HZ: u32> Ticks<HZ> {
     }
 }
 
+impl<const HZ: u32> Ticks<HZ> {
+    /// Conversion from a Duration that saturates at [Self::MAX] rather than failing
+    ///
+    /// This is useful for timeouts, where waiting for "very long" is as good as waiting for the
+    /// precise duration.
+    pub const fn from_duration_saturating(duration: core::time::Duration) -> Self {
+        Ticks(conv::duration_to_ticks(HZ, duration))
+    }
+
+    /// The duration this number of ticks amounts to
+    pub const fn to_duration(self) -> core::time::Duration {
+        conv::ticks_to_duration(HZ, self.0)
+    }
+}
+
+impl<const HZ: u32> From<Ticks<HZ>> for core::time::Duration {
+    fn from(ticks: Ticks<HZ>) -> Self {
+        ticks.to_duration()
+    }
+}
+
 impl<const HZ: u32> TryFrom<core::time::Duration> for Ticks<HZ> {
     type Error = Overflow;
 
@@ -1,4 +1,25 @@
 //! # [Periodic ZTimer API](https://doc.riot-os.org/ztimer_2periodic_8h.html)
+//!
+//! A periodic [Timer] calls its [Handler] in interrupt context at a fixed interval. The next
+//! trigger is scheduled relative to when the previous one was due (not when it ran), so delays
+//! in running the handler do not accumulate into drift.
+//!
+//! This replaces xtimer's `xtimer_periodic_wakeup`, which is used from within a thread that sleeps
+//! until the next period; with ZTimer, that is done by sleeping on a [clock](super::Clock) for
+//! the remaining time, or by having the periodic timer's handler wake up (or send a message to)
+//! the thread.
+//!
+//! Plain functions can serve as handlers:
+//!
+//! ```no_run
+//! # use core::pin::pin;
+//! # use riot_wrappers::ztimer::{Clock, Ticks, periodic::Timer};
+//! fn tick() {
+//!     // Runs in interrupt context every second
+//! }
+//! let mut timer = pin!(Timer::new(Clock::msec_unbound(), tick as fn(), Ticks(1000)));
+//! timer.start();
+//! ```
 
 use core::marker::PhantomPinned;
 use core::mem::MaybeUninit;
@@ -41,6 +62,21 @@ pub trait Handler: Send {
     fn trigger(&mut self) -> Behavior;
 }
 
+/// A plain function is a handler that keeps the timer going
+impl Handler for fn() {
+    fn trigger(&mut self) -> Behavior {
+        self();
+        Behavior::KeepGoing
+    }
+}
+
+/// A plain function is a handler that decides on whether to keep the timer going
+impl Handler for fn() -> Behavior {
+    fn trigger(&mut self) -> Behavior {
+        self()
+    }
+}
+
 /// A periodic timer
 ///
 /// This periodic timer is built on a [clock](super::Clock) and configured with a frequency and
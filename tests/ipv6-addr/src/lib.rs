@@ -0,0 +1,27 @@
+#![no_std]
+
+use riot_wrappers::gnrc::ipv6::Address;
+use riot_wrappers::println;
+use riot_wrappers::riot_main;
+
+riot_main!(main);
+
+fn check_solicited_nodes(addr: &str, expected: &str) {
+    let addr: Address = addr.parse().unwrap();
+    let expected: Address = expected.parse().unwrap();
+    let solicited = addr.solicited_nodes();
+    println!("{:?} -> {:?}", addr, solicited);
+    assert_eq!(
+        solicited.raw(),
+        expected.raw(),
+        "Wrong solicited-node address"
+    );
+}
+
+fn main() {
+    check_solicited_nodes("2001:db8::1", "ff02::1:ff00:1");
+    // Only the low 24 bits are taken over
+    check_solicited_nodes("fe80::1234:5678:9abc", "ff02::1:ff78:9abc");
+
+    println!("Done");
+}
@@ -0,0 +1,13 @@
+#!/usr/bin/env python3
+
+import os
+import sys
+from testrunner import run
+
+def test(child):
+    child.expect_exact("2001:db8::1 -> ff02::1:ff00:1")
+    child.expect_exact("fe80::1234:5678:9abc -> ff02::1:ff78:9abc")
+    child.expect("Done")
+
+if __name__ == "__main__":
+    sys.exit(run(test))
@@ -0,0 +1,28 @@
+#![no_std]
+
+use riot_wrappers::gnrc::ipv6::Address;
+use riot_wrappers::println;
+use riot_wrappers::riot_main;
+
+riot_main!(main);
+
+fn main() {
+    let address: Address = core::net::Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1).into();
+
+    // Human readable formats use the text form
+    let mut buf = [0; 64];
+    let len = serde_json_core::to_slice(&address, &mut buf).unwrap();
+    let json = core::str::from_utf8(&buf[..len]).unwrap();
+    println!("JSON: {}", json);
+    let (parsed, _): (Address, _) = serde_json_core::from_slice(&buf[..len]).unwrap();
+    assert_eq!(parsed.raw(), address.raw(), "JSON round trip failed");
+
+    // Compact formats use the bytes
+    let mut buf = [0; 64];
+    let encoded = postcard::to_slice(&address, &mut buf).unwrap();
+    println!("postcard: {:02x?}", encoded);
+    let parsed: Address = postcard::from_bytes(encoded).unwrap();
+    assert_eq!(parsed.raw(), address.raw(), "postcard round trip failed");
+
+    println!("Done");
+}
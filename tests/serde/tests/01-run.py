@@ -0,0 +1,13 @@
+#!/usr/bin/env python3
+
+import os
+import sys
+from testrunner import run
+
+def test(child):
+    child.expect_exact("JSON: \"2001:db8::1\"")
+    child.expect_exact("postcard: [10, 20, 01, 0d, b8, 00, 00, 00, 00, 00, 00, 00, 00, 00, 00, 00, 01]")
+    child.expect("Done")
+
+if __name__ == "__main__":
+    sys.exit(run(test))